@@ -0,0 +1,40 @@
+use aoc_common::{Generator, Rng};
+
+use crate::{Day01, NUMBER_STRS};
+
+/// `size` is the number of lines. Each line mixes letters, digits and spelled out digits, and
+/// has at least one digit.
+impl Generator for Day01 {
+    const DEFAULT_SIZE: usize = 1000;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let chunks = rng.index(6) + 2;
+            let digit_chunk = rng.index(chunks);
+
+            for i in 0..chunks {
+                match rng.index(3) {
+                    _ if i == digit_chunk => input.push(digit(rng)),
+                    0 => input.push(digit(rng)),
+                    1 => input.push_str(NUMBER_STRS[rng.index(9) + 1]),
+                    _ => (0..rng.index(5) + 1).for_each(|_| input.push(letter(rng))),
+                }
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+fn digit(rng: &mut Rng) -> char {
+    char::from(b'1' + rng.index(9) as u8)
+}
+
+fn letter(rng: &mut Rng) -> char {
+    char::from(b'a' + rng.index(26) as u8)
+}
@@ -0,0 +1,161 @@
+mod generate;
+
+use aoc_common::{lines, MaybeDisplay, ParseError, Solution};
+
+const NUMBER_STRS: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the sum of the calibration values, made from the first and last digit on each line
+pub fn part_one(input: &[&str]) -> u32 {
+    sum_lines(
+        input,
+        |line| {
+            line.chars()
+                .find(|a| a.is_numeric())
+                .unwrap()
+                .to_digit(10)
+                .unwrap()
+        },
+        |line| {
+            line.chars()
+                .rev()
+                .find(|a| a.is_numeric())
+                .unwrap()
+                .to_digit(10)
+                .unwrap()
+        },
+    )
+}
+
+/// As [`part_one`], but digits may also be spelled out as words
+pub fn part_two(input: &[&str]) -> u32 {
+    sum_lines(
+        input,
+        |mut line| loop {
+            if let Some(digit) = parse_str(|i| line.get(..i)) {
+                break digit;
+            }
+
+            line = &line[1..];
+        },
+        |mut line| loop {
+            if let Some(digit) = parse_str(|i| line.len().checked_sub(i).map(|i| &line[i..])) {
+                break digit;
+            }
+
+            line = &line[..line.len() - 1];
+        },
+    )
+}
+
+fn sum_lines<F, Q>(input: &[&str], tens: F, ones: Q) -> u32
+where
+    F: Fn(&str) -> u32,
+    Q: Fn(&str) -> u32,
+{
+    input.iter().map(|line| tens(line) * 10 + ones(line)).sum()
+}
+
+fn parse_str<'a, F>(get_slice: F) -> Option<u32>
+where
+    F: Fn(usize) -> Option<&'a str>,
+{
+    get_slice(1)
+        .and_then(|s| s.parse::<u32>().ok())
+        .or_else(|| {
+            NUMBER_STRS
+                .iter()
+                .enumerate()
+                .find(|(_, number_str)| get_slice(number_str.len()) == Some(**number_str))
+                .map(|(i, _)| i as u32)
+        })
+}
+
+/// Splits the calibration document into lines
+pub fn parse_input(input: &str) -> Result<Vec<&str>, ParseError> {
+    lines(input)
+        .map(|line| {
+            if let Some(offset) = line.text.find(|c: char| !c.is_ascii_alphanumeric()) {
+                return Err(line.error_at(offset, "a letter or digit"));
+            }
+
+            let has_digit = line.text.bytes().any(|b| b.is_ascii_digit())
+                || NUMBER_STRS
+                    .iter()
+                    .any(|number_str| line.text.contains(number_str));
+
+            if !has_digit {
+                return Err(line.error_at(0, "a line containing a digit"));
+            }
+
+            Ok(line.text)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+    const EXAMPLE_TWO: &str = include_str!("../example2");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 142);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE_TWO).unwrap()), 281);
+    }
+
+    #[test]
+    fn overlapping_number_words() {
+        // the last digit of `eightwo` is the two, even though it shares a letter with the eight
+        assert_eq!(part_two(&["eightwo"]), 82);
+        assert_eq!(part_two(&["oneight"]), 18);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("1abc2\npq-r\n").err(),
+            Some(ParseError::new(2, 3, "a letter or digit"))
+        );
+        assert_eq!(
+            parse_input("abc\n").err(),
+            Some(ParseError::new(1, 1, "a line containing a digit"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day01>(
+            0,
+            &[EXAMPLE, EXAMPLE_TWO, &generate::<Day01>(0, None)],
+            1000,
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day02;
+
+/// `size` is the number of games
+impl Generator for Day02 {
+    const DEFAULT_SIZE: usize = 100;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for game in 1..=size {
+            let sets: Vec<_> = (0..rng.index(6) + 1)
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+
+                    colors[..rng.index(3) + 1]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.range(1..=20)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+
+            input.push_str(&format!("Game {game}: {}\n", sets.join("; ")));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,143 @@
+mod generate;
+
+use aoc_common::{lines, Line, MaybeDisplay, ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the sum of the ids of the games which are possible with 12 red, 13 green and 14 blue
+/// cubes
+pub fn part_one(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| game_possible(game))
+        .map(|game| game.number)
+        .sum()
+}
+
+/// Returns the sum of the powers of the fewest cubes each game could have been played with
+pub fn part_two(games: &[Game]) -> u32 {
+    games.iter().map(game_power).sum()
+}
+
+pub struct Game {
+    number: u32,
+    /// All pairs `(quantity, color index)` for all sets in the game
+    dice: Vec<(u32, usize)>,
+}
+
+fn game_possible(game: &Game) -> bool {
+    game.dice
+        .iter()
+        .all(|&(quantity, color_index)| quantity <= [12, 13, 14][color_index])
+}
+
+fn game_power(game: &Game) -> u32 {
+    game.dice
+        .iter()
+        .fold([0; 3], |mut acc, &(quantity, color_index)| {
+            acc[color_index] = acc[color_index].max(quantity);
+            acc
+        })
+        .into_iter()
+        .reduce(|acc, a| acc * a)
+        .unwrap()
+}
+
+/// Parses the cubes revealed in each game
+pub fn parse_input(input: &str) -> Result<Vec<Game>, ParseError> {
+    lines(input).map(game_info).collect()
+}
+
+fn game_info(line: Line) -> Result<Game, ParseError> {
+    let (game_number, game) = line.split_once(line.text, ":")?;
+    let game_number = line.strip_prefix(game_number, "Game ")?;
+
+    Ok(Game {
+        number: line.parse(game_number, "a game number")?,
+        dice: dice_pairs(line, game).collect::<Result<_, _>>()?,
+    })
+}
+
+/// Returns an iterator over all pairs `(quantity, color index)` for all sets in the game
+fn dice_pairs<'a>(
+    line: Line<'a>,
+    game: &'a str,
+) -> impl Iterator<Item = Result<(u32, usize), ParseError>> + 'a {
+    game.split(';')
+        .flat_map(|set| set.split(','))
+        .map(move |pair| {
+            let mut iter = pair.split_whitespace();
+
+            let quantity = iter
+                .next()
+                .ok_or_else(|| line.error(pair, "a quantity"))
+                .and_then(|quantity| line.parse(quantity, "a quantity"))?;
+
+            let color = iter
+                .next()
+                .ok_or_else(|| line.error_after(pair, "a color"))?;
+
+            let index = ["red", "green", "blue"]
+                .iter()
+                .position(|label| color == *label)
+                .ok_or_else(|| line.error(color, "`red`, `green` or `blue`"))?;
+
+            Ok((quantity, index))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 8);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 2286);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("Game 1 3 blue\n").err(),
+            Some(ParseError::new(1, 14, "`:`"))
+        );
+        assert_eq!(
+            parse_input("Game 1: 3 blue\nGame x: 1 red\n").err(),
+            Some(ParseError::new(2, 6, "a game number"))
+        );
+        assert_eq!(
+            parse_input("Game 1: 3 blue; 2 purple\n").err(),
+            Some(ParseError::new(1, 19, "`red`, `green` or `blue`"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day02>(0, &[EXAMPLE, &generate::<Day02>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day03;
+
+/// `size` is the side length of the schematic, which is scattered with numbers of up to three
+/// digits and symbols.
+impl Generator for Day03 {
+    const DEFAULT_SIZE: usize = 140;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let mut row = String::new();
+
+            while row.len() < size {
+                let len = rng.index(3) + 1;
+
+                if rng.chance(0.15) && row.len() + len < size {
+                    row.push_str(
+                        &rng.range(10_i64.pow(len as u32 - 1)..=10_i64.pow(len as u32) - 1)
+                            .to_string(),
+                    );
+                    // keep numbers apart, so they don't run together
+                    row.push('.');
+                } else if rng.chance(0.1) {
+                    row.push(*rng.choose(&['*', '*', '#', '+', '$', '/', '@', '=', '%', '&', '-']));
+                } else {
+                    row.push('.');
+                }
+            }
+
+            input.push_str(&row);
+            input.push('\n');
+        }
+
+        input
+    }
+}
@@ -0,0 +1,161 @@
+mod generate;
+
+use std::{collections::HashMap, ops::Range, str};
+
+use aoc_common::{Grid, MaybeDisplay, ParseError, Point, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Parses the engine schematic
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    Grid::parse(input, "a digit, symbol or `.`", |char| {
+        char.is_ascii_graphic().then_some(char as u8)
+    })
+}
+
+/// Returns the sum of the part numbers, which are the numbers adjacent to a symbol
+pub fn part_one(grid: &Grid<u8>) -> u32 {
+    part_one_and_two(grid).0
+}
+
+/// Returns the sum of the gear ratios of every `*` adjacent to exactly two part numbers
+pub fn part_two(grid: &Grid<u8>) -> u32 {
+    part_one_and_two(grid).1
+}
+
+/// Returns `(part number sum, gear ratio sum)`
+fn part_one_and_two(grid: &Grid<u8>) -> (u32, u32) {
+    let mut part_number_sum = 0;
+    let mut ratio_sum = 0;
+
+    let mut gears = HashMap::new();
+
+    for (row_index, line) in grid.rows().enumerate() {
+        let mut col_index_start = 0;
+
+        while col_index_start < line.len() {
+            if line[col_index_start].is_ascii_digit() {
+                // we've found the start of a number, now find the end
+                let mut col_index_end = col_index_start + 1;
+
+                while col_index_end < line.len() && line[col_index_end].is_ascii_digit() {
+                    col_index_end += 1;
+                }
+
+                let part_number = str::from_utf8(&line[col_index_start..col_index_end])
+                    .unwrap()
+                    .parse::<u32>()
+                    .unwrap();
+
+                let gear_found = |ptr| {
+                    if let Some(val) = gears.remove(&ptr) {
+                        ratio_sum += part_number * val;
+                    } else {
+                        gears.insert(ptr, part_number);
+                    }
+                };
+
+                if is_range_adjacent(grid, row_index, col_index_start..col_index_end, gear_found) {
+                    part_number_sum += part_number;
+                }
+
+                col_index_start = col_index_end;
+            } else {
+                col_index_start += 1;
+            }
+        }
+    }
+
+    (part_number_sum, ratio_sum)
+}
+
+fn is_range_adjacent<F>(
+    grid: &Grid<u8>,
+    row_index: usize,
+    col_range: Range<usize>,
+    mut gear_found: F,
+) -> bool
+where
+    F: FnMut(Point),
+{
+    let mut adjacent = false;
+
+    // the rows above and below, including the diagonals, then the cells either side. Points off
+    // the top or left of the grid wrap around, so are out of bounds too.
+    let above_and_below = (col_range.start.saturating_sub(1)..col_range.end + 1)
+        .flat_map(|col| [row_index.wrapping_sub(1), row_index + 1].map(|row| Point::new(row, col)));
+    let either_side = [
+        Point::new(row_index, col_range.start.wrapping_sub(1)),
+        Point::new(row_index, col_range.end),
+    ];
+
+    for point in above_and_below.chain(either_side) {
+        match grid.get(point) {
+            Some(b'.') | None => {}
+            Some(b'*') => {
+                adjacent = true;
+                gear_found(point);
+            }
+            Some(_) => adjacent = true,
+        }
+    }
+
+    adjacent
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one_and_two(&parse_input(EXAMPLE).unwrap()).0, 4361);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_one_and_two(&parse_input(EXAMPLE).unwrap()).1, 467835);
+    }
+
+    #[test]
+    fn symbol_in_last_column() {
+        assert_eq!(part_one(&parse_input("...\n12#\n").unwrap()), 12);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("467..\n..*\n").err(),
+            Some(ParseError::new(2, 4, "5 columns"))
+        );
+        assert_eq!(
+            parse_input("4 7\n").err(),
+            Some(ParseError::new(1, 2, "a digit, symbol or `.`"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day03>(0, &[EXAMPLE, &generate::<Day03>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day04;
+
+/// `size` is the number of cards, each with 10 winning numbers and 25 numbers. Cards never win
+/// copies of cards past the end of the table.
+impl Generator for Day04 {
+    const DEFAULT_SIZE: usize = 200;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for card in 1..=size {
+            let mut numbers: Vec<_> = (1..100).collect();
+            rng.shuffle(&mut numbers);
+
+            let (winning_numbers, others) = numbers.split_at(10);
+
+            let matches = if rng.chance(0.5) { 0 } else { rng.index(11) }.min(size - card);
+
+            let mut numbers = [&winning_numbers[..matches], &others[..25 - matches]].concat();
+            rng.shuffle(&mut numbers);
+
+            let format = |numbers: &[i32]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            input.push_str(&format!(
+                "Card {card:>3}: {} | {}\n",
+                format(winning_numbers),
+                format(&numbers)
+            ));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,120 @@
+mod generate;
+
+use aoc_common::{lines, Line, MaybeDisplay, ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the total points the scratchcards are worth
+pub fn part_one(cards: &[Card]) -> u32 {
+    cards
+        .iter()
+        .map(|card| (1 << card.winning_count()) >> 1)
+        .sum()
+}
+
+/// Returns the number of scratchcards you end up with, after winning copies of the cards below
+pub fn part_two(cards: &[Card]) -> u32 {
+    let mut num_cards = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        let card_copies = num_cards[i];
+        let winning_count = card.winning_count();
+
+        for num_cards in &mut num_cards[i + 1..=i + winning_count] {
+            *num_cards += card_copies;
+        }
+    }
+
+    num_cards.into_iter().sum()
+}
+
+pub struct Card {
+    winning_numbers: Vec<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    fn winning_count(&self) -> usize {
+        self.numbers
+            .iter()
+            .filter(|n| self.winning_numbers.contains(n))
+            .count()
+    }
+}
+
+/// Parses the winning numbers and the numbers you have on each scratchcard
+pub fn parse_input(input: &str) -> Result<Vec<Card>, ParseError> {
+    lines(input).map(game_info).collect()
+}
+
+/// Parses a line of the form `Card 1: 41 48 83 | 83 86 6 31`
+fn game_info(line: Line) -> Result<Card, ParseError> {
+    let (label, numbers) = line.split_once(line.text, ":")?;
+    line.strip_prefix(label, "Card")?;
+
+    let (winning_numbers, numbers) = line.split_once(numbers, "|")?;
+
+    let parse_numbers = |numbers: &str| -> Result<Vec<u32>, ParseError> {
+        numbers
+            .split_whitespace()
+            .map(|str| line.parse(str, "a number"))
+            .collect()
+    };
+
+    Ok(Card {
+        winning_numbers: parse_numbers(winning_numbers)?,
+        numbers: parse_numbers(numbers)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 13);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 30);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("Game 1: 41 | 83\n").err(),
+            Some(ParseError::new(1, 1, "`Card`"))
+        );
+        assert_eq!(
+            parse_input("Card 1: 41 48 | 83\nCard 2: 13 x2 | 61\n").err(),
+            Some(ParseError::new(2, 12, "a number"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day04>(0, &[EXAMPLE, &generate::<Day04>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day05;
+
+const MAP_NAMES: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+/// Every value is below 2^32, as in real inputs
+const SPAN: u64 = 1 << 32;
+
+/// `size` is the total number of seeds in the seed ranges, which part two checks one by one, so
+/// the default is far fewer than the billions in real inputs.
+/// Each map shuffles a partition of all values, with a few parts left unmapped.
+impl Generator for Day05 {
+    const DEFAULT_SIZE: usize = 10_000_000;
+    const MIN_SIZE: usize = 5;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = (size as u64).clamp(5, SPAN);
+
+        // split the seeds between five ranges
+        let mut cuts: Vec<_> = (0..4).map(|_| rng.below(size + 1)).collect();
+        cuts.extend([0, size]);
+        cuts.sort_unstable();
+
+        let seeds: Vec<_> = cuts
+            .windows(2)
+            .flat_map(|cut| {
+                let len = (cut[1] - cut[0]).max(1);
+                [rng.below(SPAN - len + 1), len]
+            })
+            .map(|n| n.to_string())
+            .collect();
+
+        let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+        for name in MAP_NAMES {
+            input.push_str(&format!("\n{name} map:\n"));
+
+            let mut cuts: Vec<_> = (0..rng.range(8..=40)).map(|_| rng.below(SPAN)).collect();
+            cuts.extend([0, SPAN]);
+            cuts.sort_unstable();
+            cuts.dedup();
+
+            // lay the parts out again in a random order, to find where each is mapped to
+            let mut segments: Vec<_> = cuts
+                .windows(2)
+                .map(|cut| (cut[0], cut[1] - cut[0]))
+                .collect();
+            rng.shuffle(&mut segments);
+
+            let mut dst = 0;
+
+            for (src, len) in segments {
+                if rng.chance(0.9) {
+                    input.push_str(&format!("{dst} {src} {len}\n"));
+                }
+
+                dst += len;
+            }
+        }
+
+        input
+    }
+}
@@ -0,0 +1,203 @@
+mod generate;
+
+use std::{
+    num::NonZeroUsize,
+    sync::atomic::{AtomicU64, Ordering},
+    thread::{available_parallelism, scope},
+};
+
+use aoc_common::{lines, unexpected_end, Line, MaybeDisplay, ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the lowest location number for any of the seeds
+pub fn part_one(input: &Input) -> u64 {
+    input
+        .seeds
+        .iter()
+        .map(|seed| find_location(input, *seed))
+        .min()
+        .unwrap()
+}
+
+/// As [`part_one`], but the seeds are pairs of the start and length of a range of seeds
+pub fn part_two(input: &Input) -> u64 {
+    let parallelism = available_parallelism().map(NonZeroUsize::get).unwrap_or(1) as u64;
+    let min_location = AtomicU64::new(u64::MAX);
+
+    for chunk in input.seeds.chunks_exact(2) {
+        let seed_start = chunk[0];
+        let seed_end = chunk[0] + chunk[1];
+        let min_location = &min_location;
+
+        scope(|s| {
+            for i in 0..parallelism {
+                s.spawn(move || {
+                    let chunk_start = seed_start + (seed_end - seed_start) * i / parallelism;
+                    let chunk_end = seed_start + (seed_end - seed_start) * (i + 1) / parallelism;
+
+                    // ranges shorter than the parallelism leave some chunks empty
+                    if let Some(location) = (chunk_start..chunk_end)
+                        .map(|seed| find_location(input, seed))
+                        .min()
+                    {
+                        min_location.fetch_min(location, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+    }
+
+    min_location.into_inner()
+}
+
+fn find_location(input: &Input, mut seed: u64) -> u64 {
+    for mapping in &input.range_mappings {
+        if let Some(m) = mapping
+            .iter()
+            .find(|m| (m.src..m.src + m.range).contains(&seed))
+        {
+            seed = m.dst + (seed - m.src);
+        }
+    }
+
+    seed
+}
+
+pub struct Input {
+    seeds: Vec<u64>,
+    range_mappings: [Vec<RangeMap>; 7],
+}
+
+#[derive(Debug)]
+struct RangeMap {
+    dst: u64,
+    src: u64,
+    range: u64,
+}
+
+/// Parses the seeds and the map between each pair of categories
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut lines = lines(input);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, "`seeds:`"))?;
+
+    let seeds = line
+        .strip_prefix(line.text, "seeds:")?
+        .split_whitespace()
+        .map(|str| line.parse(str, "a seed number"))
+        .collect::<Result<_, _>>()?;
+
+    if let Some(line) = lines.next().filter(|line| !line.text.is_empty()) {
+        return Err(line.error_at(0, "an empty line"));
+    }
+
+    let mut range_mappings: [Vec<RangeMap>; 7] = Default::default();
+
+    for mapping in &mut range_mappings {
+        *mapping = parse_mapping(input, &mut lines)?;
+    }
+
+    Ok(Input {
+        seeds,
+        range_mappings,
+    })
+}
+
+/// Parses a map header followed by its ranges, up to the next empty line
+fn parse_mapping<'a, I>(input: &str, lines: &mut I) -> Result<Vec<RangeMap>, ParseError>
+where
+    I: Iterator<Item = Line<'a>>,
+{
+    let mut mapping = Vec::new();
+
+    let header = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, "a map header"))?;
+
+    if !header.text.ends_with("map:") {
+        return Err(header.error_at(0, "a map header"));
+    }
+
+    for line in lines.take_while(|line| !line.text.is_empty()) {
+        let mut vals = line.text.split_whitespace();
+
+        let dst = line.parse_next(&mut vals, "a destination range start")?;
+        let src = line.parse_next(&mut vals, "a source range start")?;
+        let range = line.parse_next(&mut vals, "a range length")?;
+
+        mapping.push(RangeMap { dst, src, range });
+    }
+
+    Ok(mapping)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 35);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 46);
+    }
+
+    #[test]
+    fn part_two_short_ranges() {
+        // ranges with fewer seeds than there are threads leave some threads without seeds
+        let short = EXAMPLE.replacen("seeds: 79 14 55 13", "seeds: 79 0 55 1", 1);
+        let single = EXAMPLE.replacen("seeds: 79 14 55 13", "seeds: 55", 1);
+
+        assert_eq!(
+            part_two(&parse_input(&short).unwrap()),
+            part_one(&parse_input(&single).unwrap())
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("seeds: 79 x\n").err(),
+            Some(ParseError::new(1, 11, "a seed number"))
+        );
+        assert_eq!(
+            parse_input("seeds: 79\n\nsoil\n").err(),
+            Some(ParseError::new(3, 1, "a map header"))
+        );
+        assert_eq!(
+            parse_input("seeds: 79\n\nseed-to-soil map:\n50 98\n").err(),
+            Some(ParseError::new(4, 6, "a range length"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day05>(0, &[EXAMPLE, &generate::<Day05>(0, None)], 1000);
+    }
+}
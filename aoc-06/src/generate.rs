@@ -0,0 +1,40 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day06;
+
+/// `size` is the number of digits in the time for part two, from 1 to 9, which is split between
+/// the races of part one. Every race can be won.
+impl Generator for Day06 {
+    const DEFAULT_SIZE: usize = 8;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut digits = size.clamp(1, 9);
+        let mut times = Vec::new();
+
+        while digits > 0 {
+            let len = (rng.index(3) + 1).min(digits);
+            times.push(rng.range(10_i64.pow(len as u32 - 1).max(2)..=10_i64.pow(len as u32) - 1));
+            digits -= len;
+        }
+
+        // the longest winning hold is half the time, so the record must be below a quarter of the
+        // time squared
+        let records: Vec<_> = times
+            .iter()
+            .map(|time| rng.range(time * time / 8..=time * time / 4 - 1))
+            .collect();
+
+        let mut time_line = "Time:    ".to_owned();
+        let mut record_line = "Distance:".to_owned();
+
+        for (time, record) in times.iter().zip(&records) {
+            let width = record.to_string().len().max(time.to_string().len());
+
+            time_line.push_str(&format!("  {time:>width$}"));
+            record_line.push_str(&format!("  {record:>width$}"));
+        }
+
+        format!("{time_line}\n{record_line}\n")
+    }
+}
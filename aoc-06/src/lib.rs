@@ -0,0 +1,154 @@
+mod generate;
+
+use aoc_common::{lines, unexpected_end, Line, MaybeDisplay, ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = (Vec<RaceInfo>, RaceInfo);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(&input.0)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(&input.1)
+    }
+}
+
+/// Returns the product of the number of ways to beat the record in each race
+pub fn part_one(race_info: &[RaceInfo]) -> u64 {
+    race_info
+        .iter()
+        .map(ways_to_win)
+        .reduce(|acc, a| acc * a)
+        .unwrap()
+}
+
+/// Returns the number of ways to beat the record in the single long race
+pub fn part_two(race_info: &RaceInfo) -> u64 {
+    ways_to_win(race_info)
+}
+
+fn ways_to_win(race_info: &RaceInfo) -> u64 {
+    (1..race_info.time)
+        .filter(|hold_time| (race_info.time - hold_time) * hold_time > race_info.record)
+        .count() as u64
+}
+
+#[derive(Debug)]
+pub struct RaceInfo {
+    time: u64,
+    record: u64,
+}
+
+/// Parses the races, both as separate races for part one and as a single race with the spaces
+/// between the digits ignored for part two
+pub fn parse_input(input: &str) -> Result<(Vec<RaceInfo>, RaceInfo), ParseError> {
+    Ok((parse_input_part_one(input)?, parse_input_part_two(input)?))
+}
+
+fn parse_input_part_one(input: &str) -> Result<Vec<RaceInfo>, ParseError> {
+    let mut lines = lines(input);
+
+    let (line, times) = labelled_line(input, &mut lines, "Time:")?;
+
+    let mut race_info = times
+        .split_whitespace()
+        .map(|str| {
+            Ok(RaceInfo {
+                time: line.parse(str, "a time")?,
+                record: 0,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if race_info.is_empty() {
+        return Err(line.error_after(line.text, "a time"));
+    }
+
+    let (line, records) = labelled_line(input, &mut lines, "Distance:")?;
+    let mut records = records.split_whitespace();
+
+    for info in &mut race_info {
+        info.record = line.parse_next(&mut records, "a record distance")?;
+    }
+
+    Ok(race_info)
+}
+
+fn parse_input_part_two(input: &str) -> Result<RaceInfo, ParseError> {
+    let mut lines = lines(input);
+
+    let (line, time) = labelled_line(input, &mut lines, "Time:")?;
+    let time = parse_kerned(line, time, "a time")?;
+
+    let (line, record) = labelled_line(input, &mut lines, "Distance:")?;
+    let record = parse_kerned(line, record, "a record distance")?;
+
+    Ok(RaceInfo { time, record })
+}
+
+/// Returns the next line along with the text following its `label`
+fn labelled_line<'a, I>(
+    input: &str,
+    lines: &mut I,
+    label: &str,
+) -> Result<(Line<'a>, &'a str), ParseError>
+where
+    I: Iterator<Item = Line<'a>>,
+{
+    let line = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, format_args!("`{label}`")))?;
+
+    Ok((line, line.strip_prefix(line.text, label)?))
+}
+
+/// Parses a number which has had spaces inserted between its digits
+fn parse_kerned(line: Line, str: &str, expected: &str) -> Result<u64, ParseError> {
+    str.split_whitespace()
+        .fold(String::new(), |acc, str| acc + str)
+        .parse()
+        .map_err(|_| line.error(str.trim_start(), expected))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input_part_one(EXAMPLE).unwrap()), 288);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input_part_two(EXAMPLE).unwrap()), 71503);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("Time: 7 15\nDistance: 9\n").err(),
+            Some(ParseError::new(2, 12, "a record distance"))
+        );
+        assert_eq!(
+            parse_input("Time: 7\nDist: 9\n").err(),
+            Some(ParseError::new(2, 1, "`Distance:`"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day06>(0, &[EXAMPLE, &generate::<Day06>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,25 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day07;
+
+const CARDS: &[u8] = b"23456789TJQKA";
+
+/// `size` is the number of hands. Cards are drawn from a few labels per hand, so that every kind
+/// of hand turns up.
+impl Generator for Day07 {
+    const DEFAULT_SIZE: usize = 1000;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let labels: Vec<_> = (0..rng.index(5) + 1).map(|_| *rng.choose(CARDS)).collect();
+            let hand: String = (0..5).map(|_| char::from(*rng.choose(&labels))).collect();
+
+            input.push_str(&format!("{hand} {}\n", rng.range(1..=1000)));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,218 @@
+mod generate;
+
+use std::cmp::Ordering;
+
+use aoc_common::{lines, MaybeDisplay, ParseError, Solution};
+
+type Cards = [u8; 5];
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Hand>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(hand: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(hand)
+    }
+
+    fn part_two(hand: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(hand)
+    }
+}
+
+/// Returns the total winnings, ranking the hands by their type and then card by card
+pub fn part_one(hand: &[Hand]) -> u32 {
+    let mut hand = hand.to_vec();
+    hand.sort_unstable_by(Hand::cmp_part_one);
+
+    hand.iter()
+        .enumerate()
+        .map(|(rank, hand)| (rank + 1) as u32 * hand.bid)
+        .sum()
+}
+
+/// As [`part_one`], but `J` cards are jokers, which make the strongest type they can but are the
+/// weakest individual card
+pub fn part_two(hand: &[Hand]) -> u32 {
+    let mut hand = hand.to_vec();
+    hand.sort_unstable_by(Hand::cmp_part_two);
+
+    hand.iter()
+        .enumerate()
+        .map(|(rank, hand)| (rank + 1) as u32 * hand.bid)
+        .sum()
+}
+
+#[derive(Clone, Debug)]
+pub struct Hand {
+    cards: Cards,
+    bid: u32,
+}
+
+impl Hand {
+    fn cmp_part_one(&self, other: &Self) -> Ordering {
+        let a_seq_lens = sequence_lengths_part_one(self.cards);
+        let b_seq_lens = sequence_lengths_part_one(other.cards);
+
+        cmp_type(a_seq_lens, b_seq_lens).unwrap_or_else(|| self.cards.cmp(&other.cards))
+    }
+
+    fn cmp_part_two(&self, other: &Self) -> Ordering {
+        let a_seq_lens = sequence_lengths_part_two(self.cards);
+        let b_seq_lens = sequence_lengths_part_two(other.cards);
+
+        cmp_type(a_seq_lens, b_seq_lens).unwrap_or_else(|| {
+            let a = self.cards.map(|card| if card == 11 { 1 } else { card });
+            let b = other.cards.map(|card| if card == 11 { 1 } else { card });
+            a.cmp(&b)
+        })
+    }
+}
+
+fn sequence_lengths_part_one(mut cards: Cards) -> [usize; 5] {
+    cards.sort_unstable();
+    sequence_lengths_slice(&cards)
+}
+
+fn sequence_lengths_part_two(cards: Cards) -> [usize; 5] {
+    let mut cards = cards.map(|card| if card == 11 { 15 } else { card });
+
+    cards.sort_unstable();
+
+    let (cards, jokers) = cards.split_at(cards.partition_point(|&a| a < 15));
+
+    let mut lengths = sequence_lengths_slice(cards);
+    lengths[0] += jokers.len();
+    lengths
+}
+
+fn sequence_lengths_slice(cards: &[u8]) -> [usize; 5] {
+    let mut length_index = 0;
+    let mut lengths = [0; 5];
+
+    let mut sequence_start = 0;
+
+    for (i, card) in cards.iter().copied().enumerate() {
+        if cards[sequence_start] != card {
+            lengths[length_index] = i - sequence_start;
+            length_index += 1;
+            sequence_start = i;
+        }
+    }
+
+    lengths[length_index] = cards.len() - sequence_start;
+
+    lengths.sort_unstable();
+    lengths.reverse();
+    lengths
+}
+
+fn cmp_type(a_seq_lens: [usize; 5], b_seq_lens: [usize; 5]) -> Option<Ordering> {
+    if a_seq_lens[0] != b_seq_lens[0] {
+        Some(a_seq_lens[0].cmp(&b_seq_lens[0]))
+    } else if a_seq_lens[0] == 3 && a_seq_lens[1] == 2 && b_seq_lens[1] != 2 {
+        Some(Ordering::Greater)
+    } else if a_seq_lens[0] == 3 && a_seq_lens[1] != 2 && b_seq_lens[1] == 2 {
+        Some(Ordering::Less)
+    } else if a_seq_lens[0] == 2 && a_seq_lens[1] == 2 && b_seq_lens[1] != 2 {
+        Some(Ordering::Greater)
+    } else if a_seq_lens[0] == 2 && a_seq_lens[1] != 2 && b_seq_lens[1] == 2 {
+        Some(Ordering::Less)
+    } else {
+        None
+    }
+}
+
+/// Parses each hand and its bid
+pub fn parse_input(input: &str) -> Result<Vec<Hand>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let mut iter = line.text.split_ascii_whitespace();
+
+            let token = line.next(&mut iter, "5 cards")?;
+            let cards =
+                Cards::try_from(token.as_bytes()).map_err(|_| line.error(token, "5 cards"))?;
+
+            for (i, card) in cards.iter().enumerate() {
+                if !b"23456789TJQKA".contains(card) {
+                    return Err(line.error(&token[i..], "a card"));
+                }
+            }
+
+            let cards = cards.map(|card| match card {
+                b'2'..=b'9' => card - b'0',
+                b'T' => 10,
+                b'J' => 11,
+                b'Q' => 12,
+                b'K' => 13,
+                _ => 14,
+            });
+
+            Ok(Hand {
+                cards,
+                bid: line.parse_next(&mut iter, "a bid")?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 6440);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 5905);
+    }
+
+    #[test]
+    fn full_house_beats_three_of_a_kind() {
+        let full_house = sequence_lengths_part_one([2, 2, 3, 3, 3]);
+        let three_of_a_kind = sequence_lengths_part_one([14, 2, 3, 3, 3]);
+
+        assert_eq!(
+            cmp_type(full_house, three_of_a_kind),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(cmp_type(three_of_a_kind, full_house), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn jokers_join_the_longest_sequence() {
+        assert_eq!(sequence_lengths_part_two([11; 5]), [5, 0, 0, 0, 0]);
+        assert_eq!(
+            sequence_lengths_part_two([13, 10, 11, 11, 10]),
+            [4, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("32T3X 765\n").err(),
+            Some(ParseError::new(1, 5, "a card"))
+        );
+        assert_eq!(
+            parse_input("32T3K\n").err(),
+            Some(ParseError::new(1, 6, "a bid"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day07>(0, &[EXAMPLE, &generate::<Day07>(0, None)], 1000);
+    }
+}
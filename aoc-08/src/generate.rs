@@ -0,0 +1,116 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day08;
+
+const LABEL_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The number of ghosts, each taking a cycle a different prime number of times the length of the
+/// directions
+const PRIMES: [u64; 6] = [2, 3, 5, 7, 11, 13];
+
+/// `size` is the number of directions, up to 1000 so that there are enough labels for the nodes.
+/// Each ghost follows a chain of nodes which reaches its `Z` node after a prime multiple of that
+/// many steps, and then starts over.
+impl Generator for Day08 {
+    const DEFAULT_SIZE: usize = 263;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_with_answers(rng, size).0
+    }
+}
+
+/// Returns the input along with the answers to both parts
+fn generate_with_answers(rng: &mut Rng, size: usize) -> (String, [u64; 2]) {
+    let len = size.clamp(1, 1000);
+    let directions: Vec<_> = (0..len).map(|_| rng.index(2)).collect();
+
+    let mut labels: Vec<String> = LABEL_CHARS
+        .iter()
+        .flat_map(|&a| LABEL_CHARS.iter().map(move |&b| [a, b]))
+        .flat_map(|ab| LABEL_CHARS.iter().map(move |&c| [ab[0], ab[1], c]))
+        .map(|label| String::from_utf8(label.to_vec()).unwrap())
+        .filter(|label| !label.ends_with('A') && !label.ends_with('Z'))
+        .collect();
+    rng.shuffle(&mut labels);
+
+    // the first two characters of the start and end labels
+    let mut ends: Vec<String> = LABEL_CHARS
+        .iter()
+        .flat_map(|&a| {
+            LABEL_CHARS
+                .iter()
+                .map(move |&b| String::from_utf8(vec![a, b]).unwrap())
+        })
+        .filter(|end| end != "AA" && end != "ZZ")
+        .collect();
+    rng.shuffle(&mut ends);
+
+    let mut primes = PRIMES;
+    rng.shuffle(&mut primes);
+
+    let mut nodes = Vec::new();
+    let mut labels = labels.into_iter();
+
+    for (ghost, &prime) in primes.iter().enumerate() {
+        let (start, end) = match ghost {
+            0 => ("AAA".to_owned(), "ZZZ".to_owned()),
+            _ => (
+                format!("{}A", ends[ghost * 2]),
+                format!("{}Z", ends[ghost * 2 + 1]),
+            ),
+        };
+
+        let steps = prime as usize * len;
+
+        let mut chain = vec![start];
+        chain.extend(labels.by_ref().take(steps - 1));
+        chain.push(end);
+
+        for (i, label) in chain.iter().enumerate() {
+            // the end node carries on from the first step after the start
+            let next = if i == steps { &chain[1] } else { &chain[i + 1] };
+            let other = rng.choose(&chain);
+
+            let mut branches = [next, other];
+
+            if directions[i % len] == 1 {
+                branches.swap(0, 1);
+            }
+
+            nodes.push(format!("{label} = ({}, {})", branches[0], branches[1]));
+        }
+    }
+
+    rng.shuffle(&mut nodes);
+
+    let directions: String = directions.iter().map(|&dir| ['L', 'R'][dir]).collect();
+    let input = format!("{directions}\n\n{}\n", nodes.join("\n"));
+
+    // the primes are distinct, so their least common multiple is their product
+    let len = len as u64;
+
+    (
+        input,
+        [primes[0] * len, PRIMES.iter().product::<u64>() * len],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Part};
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..10 {
+            let (input, answers) = generate_with_answers(&mut Rng::new(seed), 10 + seed as usize);
+            let run = solve::<Day08>(&input, &Part::ALL).unwrap();
+
+            for (answer, expected) in run.answers.iter().zip(answers) {
+                assert_eq!(answer.value, Some(expected.to_string()));
+            }
+        }
+    }
+}
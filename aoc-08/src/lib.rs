@@ -0,0 +1,268 @@
+mod generate;
+
+use std::{collections::HashMap, iter::zip};
+
+use aoc_common::{lines, unexpected_end, Line, MaybeAnswer, MaybeDisplay, ParseError, Solution};
+use num::integer::lcm;
+
+pub type Loc = [u8; 3];
+pub type Map = HashMap<Loc, [Loc; 2]>;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = (Vec<usize>, Map);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_one(&input.0, &input.1), "no path from `AAA` to `ZZZ`")
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(
+            part_two(&input.0, &input.1),
+            "no ghosts, or one never reaches a node ending in `Z`",
+        )
+    }
+}
+
+/// Returns the number of steps from `AAA` to `ZZZ`, or `None` if there's no `AAA` or `ZZZ` is
+/// never reached
+pub fn part_one(directions: &[usize], map: &Map) -> Option<u64> {
+    step_count(*b"AAA", directions, map, |loc| loc == *b"ZZZ")
+}
+
+/// Returns the number of steps until every ghost starting on a node ending in `A` is on a node
+/// ending in `Z`. Like the real inputs, each ghost must reach its `Z` node after a multiple of the
+/// number of directions and then start over from the first step.
+///
+/// Returns `None` if there are no ghosts, or one never reaches a node ending in `Z`.
+pub fn part_two(directions: &[usize], map: &Map) -> Option<u64> {
+    map.keys()
+        .filter(|key| key[2] == b'A')
+        .map(|&loc| step_count(loc, directions, map, |loc| loc[2] == b'Z'))
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .reduce(lcm)
+}
+
+/// Returns the number of steps from `location` to a location matching `condition`, or `None` if
+/// `location` isn't in the map or no such location is reached.
+fn step_count<F>(mut location: Loc, directions: &[usize], map: &Map, condition: F) -> Option<u64>
+where
+    F: Fn(Loc) -> bool,
+{
+    // the walk repeats once it's on the same location at the same point in the directions, which
+    // happens within this many steps
+    let states = map.len() * directions.len();
+
+    for (count, dir) in directions.iter().cycle().take(states + 1).enumerate() {
+        if condition(location) {
+            return Some(count as u64);
+        }
+
+        location = map.get(&location)?[*dir];
+    }
+
+    None
+}
+
+/// Parses the directions, as `0` for left and `1` for right, and the network of nodes
+pub fn parse_input(input: &str) -> Result<(Vec<usize>, Map), ParseError> {
+    let mut lines = lines(input);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, "directions"))?;
+
+    let directions = line
+        .text
+        .char_indices()
+        .map(|(i, char)| match char {
+            'L' => Ok(0),
+            'R' => Ok(1),
+            _ => Err(line.error_at(i, "`L` or `R`")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if directions.is_empty() {
+        return Err(line.error_at(0, "directions"));
+    }
+
+    if let Some(line) = lines.next() {
+        if !line.text.is_empty() {
+            return Err(line.error_at(0, "an empty line"));
+        }
+    }
+
+    let mut map = Map::new();
+    let mut references = Vec::new();
+
+    for line in lines {
+        let (src, dst) = line.split_once(line.text, " = ")?;
+        let dst = line.strip_prefix(dst, "(")?;
+        let (left, right) = line.split_once(dst, ", ")?;
+        let (right, rest) = line.split_once(right, ")")?;
+
+        if !rest.is_empty() {
+            return Err(line.error(rest, "the end of the line"));
+        }
+
+        let dst = [location(line, left)?, location(line, right)?];
+
+        for (loc, str) in zip(dst, [left, right]) {
+            references.push((loc, line.error(str, "a known location")));
+        }
+
+        map.insert(location(line, src)?, dst);
+    }
+
+    if let Some((_, err)) = references
+        .into_iter()
+        .find(|(loc, _)| !map.contains_key(loc))
+    {
+        return Err(err);
+    }
+
+    Ok((directions, map))
+}
+
+fn location(line: Line, str: &str) -> Result<Loc, ParseError> {
+    str.as_bytes()
+        .try_into()
+        .ok()
+        .filter(|loc: &Loc| loc.iter().all(u8::is_ascii_alphanumeric))
+        .ok_or_else(|| line.error(str, "a 3 character location"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+    use proptest::{collection::vec, prelude::*, sample::Index};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+    const EXAMPLE_TWO: &str = include_str!("../example2");
+    const EXAMPLE_THREE: &str = include_str!("../example3");
+
+    #[test]
+    fn part_one_example() {
+        let (directions, map) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&directions, &map), Some(2));
+
+        let (directions, map) = parse_input(EXAMPLE_TWO).unwrap();
+        assert_eq!(part_one(&directions, &map), Some(6));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let (directions, map) = parse_input(EXAMPLE_THREE).unwrap();
+        assert_eq!(part_two(&directions, &map), Some(6));
+    }
+
+    #[test]
+    fn unreachable_ends() {
+        // no `AAA`, and no ghost
+        let (directions, map) = parse_input("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(part_one(&directions, &map), None);
+        assert_eq!(part_two(&directions, &map), None);
+
+        // `AAA` loops back to itself without reaching `ZZZ`
+        let (directions, map) =
+            parse_input("L\n\nAAA = (BBB, AAA)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+        assert_eq!(part_one(&directions, &map), None);
+        assert_eq!(part_two(&directions, &map), None);
+    }
+
+    #[test]
+    fn unknown_location() {
+        assert_eq!(
+            parse_input("L\n\nAAA = (ZZQ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").err(),
+            Some(ParseError::new(3, 8, "a known location"))
+        );
+        assert_eq!(
+            parse_input("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, BBB)\n").err(),
+            Some(ParseError::new(4, 13, "a known location"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day08>(
+            0,
+            &[
+                EXAMPLE,
+                EXAMPLE_TWO,
+                EXAMPLE_THREE,
+                &generate::<Day08>(0, None),
+            ],
+            1000,
+        );
+    }
+
+    /// Follows every ghost at once until they're all on a `Z` node
+    fn part_two_naive(directions: &[usize], map: &Map) -> u64 {
+        let mut locations: Vec<_> = map.keys().filter(|key| key[2] == b'A').copied().collect();
+        let mut count = 0;
+
+        for dir in directions.iter().cycle() {
+            if locations.iter().all(|loc| loc[2] == b'Z') {
+                break;
+            }
+
+            for loc in &mut locations {
+                *loc = map[loc][*dir];
+            }
+
+            count += 1;
+        }
+
+        count
+    }
+
+    /// Returns a map with a ghost for each of `multiples`, which reaches its `Z` node after that
+    /// many times the length of the directions and then carries on from the first step after its
+    /// start. The other branch from each node goes to the node picked by `branches`.
+    fn ghost_map(directions: &[usize], multiples: &[usize], branches: &[Index]) -> Map {
+        let mut map = Map::new();
+
+        for (ghost, multiple) in multiples.iter().enumerate() {
+            let steps = multiple * directions.len();
+            let prefix = b'a' + ghost as u8;
+
+            let chain: Vec<Loc> = (0..=steps)
+                .map(|i| match i {
+                    0 => [prefix, b'0', b'A'],
+                    _ if i == steps => [prefix, b'0', b'Z'],
+                    _ => [prefix, b'a' + (i / 26) as u8, b'a' + (i % 26) as u8],
+                })
+                .collect();
+
+            for (i, loc) in chain.iter().enumerate() {
+                let dir = directions[i % directions.len()];
+                let mut branch = [chain[branches[i].index(chain.len())]; 2];
+                branch[dir] = chain[if i == steps { 1 } else { i + 1 }];
+
+                map.insert(*loc, branch);
+            }
+        }
+
+        map
+    }
+
+    proptest! {
+        #[test]
+        fn part_two_matches_naive(
+            directions in vec(0..2usize, 1..5),
+            multiples in vec(1..5usize, 1..5),
+            branches in vec(any::<Index>(), 17),
+        ) {
+            let map = ghost_map(&directions, &multiples, &branches);
+            prop_assert_eq!(part_two(&directions, &map), Some(part_two_naive(&directions, &map)));
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day09;
+
+/// The number of values in each history
+const LEN: i64 = 21;
+
+/// `size` is the number of histories. Each is a polynomial of degree at most 8, so its
+/// differences reach zero well before they run out.
+impl Generator for Day09 {
+    const DEFAULT_SIZE: usize = 200;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            // the coefficients of the binomials `n choose k`, which are the first values of each
+            // row of differences
+            let coefficients: Vec<_> = (0..=rng.range(0..=8)).map(|_| rng.range(-5..=5)).collect();
+
+            let history: Vec<_> = (0..LEN)
+                .map(|n| {
+                    coefficients
+                        .iter()
+                        .zip(0..)
+                        .map(|(a, k)| a * choose(n, k))
+                        .sum::<i64>()
+                        .to_string()
+                })
+                .collect();
+
+            input.push_str(&history.join(" "));
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+fn choose(n: i64, k: i64) -> i64 {
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
@@ -0,0 +1,118 @@
+mod generate;
+
+use aoc_common::{lines, MaybeDisplay, ParseError, Solution};
+
+pub type History = Vec<i64>;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<History>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the sum of the next value extrapolated from each history
+pub fn part_one(input: &[History]) -> i64 {
+    input
+        .iter()
+        .map(|a| a.last().unwrap() + extrapolate(a))
+        .sum()
+}
+
+/// Returns the sum of the previous value extrapolated from each history
+pub fn part_two(input: &[History]) -> i64 {
+    input
+        .iter()
+        .map(|row| {
+            let mut row = row.clone();
+            row.reverse();
+            row.last().unwrap() + extrapolate(&row)
+        })
+        .sum()
+}
+
+fn extrapolate(row: &[i64]) -> i64 {
+    if row.iter().all(|a| a == &0) {
+        return 0;
+    }
+
+    let row = row
+        .windows(2)
+        .map(|window| window[1] - window[0])
+        .collect::<Vec<_>>();
+
+    row.last().unwrap() + extrapolate(&row)
+}
+
+/// Parses the history of each value
+pub fn parse_input(input: &str) -> Result<Vec<History>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let history = line
+                .text
+                .split_whitespace()
+                .map(|str| line.parse(str, "a value"))
+                .collect::<Result<History, _>>()?;
+
+            if history.is_empty() {
+                return Err(line.error_at(0, "a value"));
+            }
+
+            Ok(history)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 114);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 2);
+    }
+
+    #[test]
+    fn extrapolate_next_values() {
+        assert_eq!(extrapolate(&[0, 3, 6, 9, 12, 15]), 3);
+        assert_eq!(extrapolate(&[1, 3, 6, 10, 15, 21]), 7);
+        assert_eq!(extrapolate(&[10, 13, 16, 21, 30, 45]), 23);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("0 3 6\n1 x 5\n").err(),
+            Some(ParseError::new(2, 3, "a value"))
+        );
+        assert_eq!(
+            parse_input("0 3 6\n\n").err(),
+            Some(ParseError::new(2, 1, "a value"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day09>(0, &[EXAMPLE, &generate::<Day09>(0, None)], 1000);
+    }
+}
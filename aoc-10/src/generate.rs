@@ -0,0 +1,66 @@
+use aoc_common::{random_loop, Dir, Generator, Point, Rng};
+
+use crate::Day10;
+
+/// `size` is the side length of the grid. The tiles off the loop are random pipes and ground.
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 140;
+    const MIN_SIZE: usize = 3;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(3);
+        let mut grid: Vec<Vec<u8>> = (0..size)
+            .map(|_| (0..size).map(|_| *rng.choose(b"|-LJ7F..")).collect())
+            .collect();
+
+        // the points of the loop are tiles, so the loop runs alongside itself wherever the region
+        // it bounds is narrow, and only encloses the tiles surrounded by the region. Like real
+        // inputs, the region is mostly narrow, so few tiles are enclosed.
+        let tiles = random_loop(rng, size - 1, size - 1, 0.02);
+
+        for (i, tile) in tiles.iter().enumerate() {
+            let prev = tiles[(i + tiles.len() - 1) % tiles.len()];
+            let next = tiles[(i + 1) % tiles.len()];
+
+            let mut dirs = [direction(*tile, prev), direction(*tile, next)];
+            dirs.sort_unstable();
+
+            grid[tile[0]][tile[1]] = match dirs {
+                [Dir::Up, Dir::Down] => b'|',
+                [Dir::Right, Dir::Left] => b'-',
+                [Dir::Up, Dir::Right] => b'L',
+                [Dir::Up, Dir::Left] => b'J',
+                [Dir::Down, Dir::Left] => b'7',
+                _ => b'F',
+            };
+        }
+
+        let start = *rng.choose(&tiles);
+        grid[start[0]][start[1]] = b'S';
+
+        // only the pipes on the loop may connect to the start
+        for [dy, dx] in [[-1, 0], [1, 0], [0, -1], [0, 1]] {
+            let (Some(y), Some(x)) = (
+                start[0].checked_add_signed(dy).filter(|&y| y < size),
+                start[1].checked_add_signed(dx).filter(|&x| x < size),
+            ) else {
+                continue;
+            };
+
+            if !tiles.contains(&[y, x]) {
+                grid[y][x] = b'.';
+            }
+        }
+
+        grid.into_iter()
+            .map(|row| String::from_utf8(row).unwrap() + "\n")
+            .collect()
+    }
+}
+
+/// Returns the direction of the adjacent tile `to` from `from`
+fn direction(from: [usize; 2], to: [usize; 2]) -> Dir {
+    Point::new(from[0], from[1])
+        .dir_to(Point::new(to[0], to[1]))
+        .unwrap()
+}
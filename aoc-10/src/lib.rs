@@ -0,0 +1,294 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_common::{unexpected_end, Dir, Grid, MaybeDisplay, ParseError, Point, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the number of steps from the start to the furthest point along the loop
+pub fn part_one(grid: &Grid<u8>) -> u32 {
+    trace_loop(grid, &mut HashSet::new())
+}
+
+/// Returns the number of tiles enclosed by the loop
+pub fn part_two(grid: &Grid<u8>) -> u32 {
+    // Contained points are twice as big, to accomodate space between pipes
+    let mut pipe_coords = HashSet::new();
+    trace_loop(grid, &mut pipe_coords);
+
+    enclosed_count(grid, &pipe_coords)
+}
+
+/// Returns the number of steps to the farthest point of the loop
+fn trace_loop(grid: &Grid<u8>, pipe_coords: &mut HashSet<Point>) -> u32 {
+    let mut positions = Position::start_pair(grid);
+    let mut steps = 1;
+
+    // insert starting point
+    pipe_coords.insert(double(positions[0].prev));
+
+    while positions[0].curr != positions[1].curr {
+        for position in &mut positions {
+            insert_pipe_coords(position, pipe_coords);
+
+            // follow the pipe
+            position.increment(grid);
+        }
+
+        steps += 1;
+    }
+
+    for position in &positions {
+        insert_pipe_coords(position, pipe_coords);
+    }
+
+    steps
+}
+
+fn enclosed_count(grid: &Grid<u8>, pipe_coords: &HashSet<Point>) -> u32 {
+    // the bottom right corner of the doubled grid, which ends on the last row and column of tiles
+    let corner = double(Point::new(grid.height() - 1, grid.width() - 1));
+
+    grid.points()
+        .map(double)
+        .filter(|point| !pipe_coords.contains(point))
+        .filter(|point| !is_outside(point, corner, pipe_coords))
+        .count() as u32
+}
+
+/// Parses the grid of pipes
+pub fn parse_input(input: &str) -> Result<Grid<u8>, ParseError> {
+    let grid = Grid::parse(input, "a pipe, `.` or `S`", |char| {
+        "|-LJ7F.S".contains(char).then_some(char as u8)
+    })?;
+
+    let mut starts = grid.points().filter(|&point| grid[point] == b'S');
+
+    let Some(start) = starts.next() else {
+        return Err(unexpected_end(input, "a start `S`"));
+    };
+
+    if let Some(Point { y, x }) = starts.next() {
+        return Err(ParseError::new(y + 1, x + 1, "a single start"));
+    }
+
+    if start_neighbours(&grid, start).count() != 2 {
+        return Err(ParseError::new(
+            start.y + 1,
+            start.x + 1,
+            "a start joined to two pipes",
+        ));
+    }
+
+    // follow the loop round from the start, so the solution can trust every pipe along it
+    let mut position = Position {
+        prev: start,
+        curr: start_neighbours(&grid, start).next().unwrap(),
+    };
+
+    while position.curr != start {
+        let next = adjacent_pipes(&grid, position.curr)
+            .into_iter()
+            .flatten()
+            .find(|&next| next != position.prev)
+            .unwrap();
+
+        if next != start && !connects(&grid, next, position.curr) {
+            return Err(ParseError::new(
+                next.y + 1,
+                next.x + 1,
+                "a pipe continuing the loop",
+            ));
+        }
+
+        position = Position {
+            prev: position.curr,
+            curr: next,
+        };
+    }
+
+    Ok(grid)
+}
+
+/// Returns the pipes next to `start` which connect to it
+fn start_neighbours(grid: &Grid<u8>, start: Point) -> impl Iterator<Item = Point> + '_ {
+    grid.neighbours(start)
+        .filter(move |&point| connects(grid, point, start))
+}
+
+/// Returns true if `point` is a pipe which leads to `other`
+fn connects(grid: &Grid<u8>, point: Point, other: Point) -> bool {
+    adjacent_pipes(grid, point).is_some_and(|adjacent| adjacent.contains(&other))
+}
+
+fn start_point(grid: &Grid<u8>) -> Point {
+    grid.position(|&tile| tile == b'S').unwrap()
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Position {
+    prev: Point,
+    curr: Point,
+}
+
+impl Position {
+    fn start_pair(grid: &Grid<u8>) -> [Self; 2] {
+        let prev = start_point(grid);
+
+        start_neighbours(grid, prev)
+            .map(|curr| Self { prev, curr })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap()
+    }
+
+    fn increment(&mut self, grid: &Grid<u8>) {
+        let adjacent = adjacent_pipes(grid, self.curr).unwrap();
+        let next = adjacent.iter().find(|&p| p != &self.prev).unwrap();
+
+        self.prev = self.curr;
+        self.curr = *next;
+    }
+}
+
+fn double(point: Point) -> Point {
+    Point::new(point.y * 2, point.x * 2)
+}
+
+/// Returns the points up to `corner` adjacent to `point`
+fn adjacent_points(point: Point, corner: Point) -> impl Iterator<Item = Point> {
+    Dir::ALL.into_iter().filter_map(move |dir| {
+        point
+            .step(dir)
+            .filter(|next| next.y <= corner.y && next.x <= corner.x)
+    })
+}
+
+/// Returns the points the pipe at `point` connects, or `None` if it isn't a pipe or it leads off
+/// the grid
+fn adjacent_pipes(grid: &Grid<u8>, point: Point) -> Option<[Point; 2]> {
+    let dirs = match grid[point] {
+        b'|' => [Dir::Up, Dir::Down],
+        b'-' => [Dir::Left, Dir::Right],
+        b'L' => [Dir::Up, Dir::Right],
+        b'J' => [Dir::Up, Dir::Left],
+        b'7' => [Dir::Down, Dir::Left],
+        b'F' => [Dir::Down, Dir::Right],
+        _ => return None,
+    };
+
+    let [a, b] = dirs.map(|dir| grid.step(point, dir));
+    Some([a?, b?])
+}
+
+fn insert_pipe_coords(position: &Position, pipe_coords: &mut HashSet<Point>) {
+    // insert current point (doubled) and the point between it and the previous one, which is the
+    // sum of the two
+    let intermediate = Point::new(
+        position.prev.y + position.curr.y,
+        position.prev.x + position.curr.x,
+    );
+
+    pipe_coords.insert(intermediate);
+    pipe_coords.insert(double(position.curr));
+}
+
+fn is_outside(point: &Point, corner: Point, pipe_coords: &HashSet<Point>) -> bool {
+    is_outside_recursive(*point, corner, pipe_coords, &mut HashSet::new())
+}
+
+fn is_outside_recursive(
+    point: Point,
+    corner: Point,
+    pipe_coords: &HashSet<Point>,
+    visited: &mut HashSet<Point>,
+) -> bool {
+    if visited.contains(&point) {
+        return false;
+    }
+
+    if pipe_coords.contains(&point) {
+        return false;
+    }
+
+    if on_edge(point, corner) {
+        return true;
+    }
+
+    visited.insert(point);
+
+    adjacent_points(point, corner)
+        .any(|point| is_outside_recursive(point, corner, pipe_coords, visited))
+}
+
+fn on_edge(point: Point, corner: Point) -> bool {
+    point.y == 0 || point.x == 0 || point.y == corner.y || point.x == corner.x
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+    const EXAMPLE_TWO: &str = include_str!("../example2");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 8);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE_TWO).unwrap()), 10);
+    }
+
+    #[test]
+    fn broken_loops() {
+        assert_eq!(
+            parse_input("S..\n...\n").err(),
+            Some(ParseError::new(1, 1, "a start joined to two pipes"))
+        );
+        assert_eq!(
+            parse_input("-S-\n.|.\n").err(),
+            Some(ParseError::new(1, 2, "a start joined to two pipes"))
+        );
+
+        // the `|` under the `7` doesn't lead back to the start
+        assert_eq!(
+            parse_input("S-7\n|.|\nL-.\n").err(),
+            Some(ParseError::new(3, 3, "a pipe continuing the loop"))
+        );
+        assert_eq!(
+            parse_input("S-7\n|.|\nL-|\n").err(),
+            Some(ParseError::new(3, 3, "a pipe continuing the loop"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day10>(
+            0,
+            &[EXAMPLE, EXAMPLE_TWO, &generate::<Day10>(0, None)],
+            1000,
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day11;
+
+/// `size` is the side length of the image. A few rows and columns are left empty, and the rest
+/// are sparsely scattered with galaxies.
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 140;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let empty_rows: Vec<_> = (0..size).map(|_| rng.chance(0.08)).collect();
+        let empty_cols: Vec<_> = (0..size).map(|_| rng.chance(0.08)).collect();
+
+        let mut input = String::new();
+
+        for &empty_row in &empty_rows {
+            for &empty_col in &empty_cols {
+                let galaxy = !empty_row && !empty_col && rng.chance(0.02);
+                input.push(if galaxy { '#' } else { '.' });
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
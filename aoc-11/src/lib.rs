@@ -0,0 +1,185 @@
+mod generate;
+
+use aoc_common::{lines, MaybeDisplay, ParseError, Point, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Input;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the sum of the distances between every pair of galaxies, where each empty row and
+/// column is twice as big
+pub fn part_one(input: &Input) -> usize {
+    distances(input, 2)
+}
+
+/// As [`part_one`], but each empty row and column is a million times as big
+pub fn part_two(input: &Input) -> usize {
+    distances(input, 1000000)
+}
+
+fn distances(input: &Input, multiplier: usize) -> usize {
+    input
+        .galaxies
+        .iter()
+        .enumerate()
+        .flat_map(|(i, point)| {
+            input.galaxies[i..].iter().map(|other| {
+                let extra_rows = (other.y..point.y)
+                    .chain(point.y..other.y)
+                    .filter(|i| input.empty_rows.contains(i))
+                    .count();
+
+                let extra_cols = (other.x..point.x)
+                    .chain(point.x..other.x)
+                    .filter(|i| input.empty_cols.contains(i))
+                    .count();
+
+                point.manhattan(*other) + (extra_rows + extra_cols) * (multiplier - 1)
+            })
+        })
+        .sum()
+}
+
+pub struct Input {
+    galaxies: Vec<Point>,
+    empty_rows: Vec<usize>,
+    empty_cols: Vec<usize>,
+}
+
+/// Parses the galaxies in the image, along with its empty rows and columns
+pub fn parse_input(input: &str) -> Result<Input, ParseError> {
+    let mut galaxies = Vec::new();
+
+    for line in lines(input) {
+        for (j, char) in line.text.char_indices() {
+            match char {
+                '#' => galaxies.push(Point::new(line.index, j)),
+                '.' => {}
+                _ => return Err(line.error_at(j, "`#` or `.`")),
+            }
+        }
+    }
+
+    let height = input.lines().count();
+    let width = input.lines().map(str::len).max().unwrap_or(0);
+
+    let empty_rows = (0..height)
+        .filter(|i| galaxies.iter().all(|galaxy| *i != galaxy.y))
+        .collect();
+
+    let empty_cols = (0..width)
+        .filter(|i| galaxies.iter().all(|galaxy| *i != galaxy.x))
+        .collect();
+
+    Ok(Input {
+        galaxies,
+        empty_rows,
+        empty_cols,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 374);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(distances(&input, 10), 1030);
+        assert_eq!(distances(&input, 100), 8410);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("#.\n.*\n").err(),
+            Some(ParseError::new(2, 2, "`#` or `.`"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day11>(0, &[EXAMPLE, &generate::<Day11>(0, None)], 1000);
+    }
+
+    /// Copies each empty row and column `multiplier - 1` times, then sums the distances between
+    /// the galaxies in the expanded image
+    fn distances_naive(image: &[Vec<bool>], multiplier: usize) -> usize {
+        let empty_cols: Vec<_> = (0..image[0].len())
+            .map(|j| image.iter().all(|row| !row[j]))
+            .collect();
+
+        let mut expanded = Vec::new();
+
+        for row in image {
+            let row: Vec<_> = row
+                .iter()
+                .zip(&empty_cols)
+                .flat_map(|(&galaxy, &empty)| vec![galaxy; if empty { multiplier } else { 1 }])
+                .collect();
+
+            let copies = if row.contains(&true) { 1 } else { multiplier };
+            expanded.extend(vec![row; copies]);
+        }
+
+        let galaxies: Vec<Point> = expanded
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.iter()
+                    .enumerate()
+                    .filter(|(_, &galaxy)| galaxy)
+                    .map(move |(j, _)| Point::new(i, j))
+            })
+            .collect();
+
+        galaxies
+            .iter()
+            .enumerate()
+            .flat_map(|(i, a)| galaxies[i + 1..].iter().map(move |b| a.manhattan(*b)))
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn distances_match_naive(
+            image in (1..8usize).prop_flat_map(|width| vec(vec(any::<bool>(), width), 1..8)),
+            multiplier in 1..10usize,
+        ) {
+            let text: String = image
+                .iter()
+                .map(|row| row.iter().map(|&galaxy| if galaxy { '#' } else { '.' }).collect::<String>() + "\n")
+                .collect();
+
+            prop_assert_eq!(
+                distances(&parse_input(&text).unwrap(), multiplier),
+                distances_naive(&image, multiplier),
+            );
+        }
+    }
+}
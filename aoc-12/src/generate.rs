@@ -0,0 +1,45 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day12;
+
+/// The most springs in a row, as in real inputs
+const MAX_LEN: usize = 20;
+
+/// `size` is the number of rows. Each row is made from its groups, and then has about half of
+/// its springs hidden, so it always has at least one arrangement.
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 1000;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            let mut groups = Vec::new();
+            let mut springs = ".".repeat(rng.index(3));
+
+            for _ in 0..rng.index(6) + 1 {
+                let len = rng.index(5) + 1;
+
+                if springs.len() + len > MAX_LEN {
+                    break;
+                }
+
+                springs.push_str(&"#".repeat(len));
+                springs.push_str(&".".repeat(rng.index(3) + 1));
+                groups.push(len.to_string());
+            }
+
+            springs.truncate(MAX_LEN);
+
+            let springs: String = springs
+                .chars()
+                .map(|spring| if rng.chance(0.5) { '?' } else { spring })
+                .collect();
+
+            input.push_str(&format!("{springs} {}\n", groups.join(",")));
+        }
+
+        input
+    }
+}
@@ -0,0 +1,170 @@
+mod generate;
+
+use aoc_common::{lines, MaybeDisplay, ParseError, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<Record>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the sum of the number of arrangements of the springs which fit each row's groups
+pub fn part_one(records: &[Record]) -> u64 {
+    records.iter().map(arrangements).sum()
+}
+
+/// As [`part_one`], but each row is unfolded into five copies of itself
+pub fn part_two(records: &[Record]) -> u64 {
+    records
+        .iter()
+        .map(|record| arrangements(&record.unfolded()))
+        .sum()
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Spring {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+#[derive(Debug)]
+pub struct Record {
+    springs: Vec<Spring>,
+    groups: Vec<usize>,
+}
+
+impl Record {
+    fn unfolded(&self) -> Self {
+        let mut springs = self.springs.clone();
+        let mut groups = self.groups.clone();
+
+        for _ in 0..4 {
+            springs.push(Spring::Unknown);
+            springs.extend(&self.springs);
+            groups.extend(&self.groups);
+        }
+
+        Self { springs, groups }
+    }
+
+    /// Returns true if a damaged group of length `len` can start at spring `i`
+    fn group_fits(&self, i: usize, len: usize) -> bool {
+        i + len <= self.springs.len()
+            && self.springs[i..i + len]
+                .iter()
+                .all(|&spring| spring != Spring::Operational)
+            && self.springs.get(i + len) != Some(&Spring::Damaged)
+    }
+}
+
+fn arrangements(record: &Record) -> u64 {
+    let mut memo = vec![None; record.springs.len() * (record.groups.len() + 1)];
+    arrangements_recursive(record, 0, 0, &mut memo)
+}
+
+/// Returns the number of arrangements of `springs[i..]` which match `groups[j..]`
+fn arrangements_recursive(record: &Record, i: usize, j: usize, memo: &mut [Option<u64>]) -> u64 {
+    let Some(&spring) = record.springs.get(i) else {
+        return (j == record.groups.len()) as u64;
+    };
+
+    let key = i * (record.groups.len() + 1) + j;
+
+    if let Some(count) = memo[key] {
+        return count;
+    }
+
+    let mut count = 0;
+
+    if spring != Spring::Damaged {
+        count += arrangements_recursive(record, i + 1, j, memo);
+    }
+
+    if spring != Spring::Operational {
+        if let Some(&len) = record.groups.get(j) {
+            if record.group_fits(i, len) {
+                // skip past the group and the operational spring which must follow it
+                let next = (i + len + 1).min(record.springs.len());
+                count += arrangements_recursive(record, next, j + 1, memo);
+            }
+        }
+    }
+
+    memo[key] = Some(count);
+    count
+}
+
+/// Parses each row of springs along with the sizes of its groups of damaged springs
+pub fn parse_input(input: &str) -> Result<Vec<Record>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (springs, groups) = line.split_once(line.text, " ")?;
+
+            let springs = springs
+                .char_indices()
+                .map(|(i, char)| match char {
+                    '.' => Ok(Spring::Operational),
+                    '#' => Ok(Spring::Damaged),
+                    '?' => Ok(Spring::Unknown),
+                    _ => Err(line.error_at(i, "`.`, `#` or `?`")),
+                })
+                .collect::<Result<_, _>>()?;
+
+            let groups = groups
+                .split(',')
+                .map(|str| line.parse(str, "a group size"))
+                .collect::<Result<_, _>>()?;
+
+            Ok(Record { springs, groups })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 21);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 525152);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("?#x 1\n").err(),
+            Some(ParseError::new(1, 3, "`.`, `#` or `?`"))
+        );
+        assert_eq!(
+            parse_input("???.### 1,1,3\n.??..?? 1,x\n").err(),
+            Some(ParseError::new(2, 11, "a group size"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day12>(0, &[EXAMPLE, &generate::<Day12>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_12::Day12;
+
+fn main() -> ExitCode {
+    aoc_common::day_main::<Day12>()
+}
@@ -0,0 +1,69 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day13;
+
+/// `size` is the number of patterns. Each has a reflection between columns, and a smudge which
+/// breaks a reflection between rows, possibly with its rows and columns swapped.
+impl Generator for Day13 {
+    const DEFAULT_SIZE: usize = 100;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let patterns: Vec<_> = (0..size).map(|_| pattern(rng)).collect();
+        patterns.join("\n")
+    }
+}
+
+fn pattern(rng: &mut Rng) -> String {
+    let height = rng.index(13) + 5;
+    let width = rng.index(13) + 5;
+
+    let mut grid: Vec<Vec<bool>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.chance(0.5)).collect())
+        .collect();
+
+    // reflect the columns left of `col` onto those right of it, leaving a column beyond the
+    // reflection for the smudge
+    let col = rng.index((width - 1) / 2) + 1;
+
+    for row in &mut grid {
+        for i in 0..col {
+            row[col + i] = row[col - 1 - i];
+        }
+    }
+
+    // then the rows above `row` onto those below it, which keeps the columns reflected
+    let row = rng.index(height / 2) + 1;
+
+    for i in 0..row {
+        grid[row + i] = grid[row - 1 - i].clone();
+    }
+
+    let smudge = [rng.index(row * 2), rng.index(width - col * 2) + col * 2];
+    grid[smudge[0]][smudge[1]] ^= true;
+
+    if rng.chance(0.5) {
+        grid.reverse();
+    }
+
+    if rng.chance(0.5) {
+        grid.iter_mut().for_each(|row| row.reverse());
+    }
+
+    if rng.chance(0.5) {
+        grid = (0..width)
+            .map(|x| grid.iter().map(|row| row[x]).collect())
+            .collect();
+    }
+
+    grid.iter()
+        .map(|row| {
+            let mut row: String = row
+                .iter()
+                .map(|&rock| if rock { '#' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
@@ -0,0 +1,200 @@
+mod generate;
+
+use std::iter::zip;
+
+use aoc_common::{lines, Grid, MaybeAnswer, MaybeDisplay, ParseError, Solution};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_one(input), "a pattern has no line of reflection")
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(
+            part_two(input),
+            "a pattern has no smudge moving its reflection",
+        )
+    }
+}
+
+/// Returns the summary of the lines of reflection in the patterns, or `None` if a pattern has no
+/// line of reflection
+pub fn part_one(patterns: &[Pattern]) -> Option<u32> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            cols_left_of_reflection(pattern)
+                .next()
+                .or_else(|| Some(rows_above_reflection(pattern).next()? * 100))
+        })
+        .sum()
+}
+
+/// As [`part_one`], but each pattern has a smudge, and fixing it gives a different line of reflection
+pub fn part_two(patterns: &[Pattern]) -> Option<u32> {
+    patterns
+        .iter()
+        .map(|pattern| {
+            alt_cols_left_of_reflection(pattern)
+                .or_else(|| Some(alt_rows_above_reflection(pattern)? * 100))
+        })
+        .sum()
+}
+
+fn cols_left_of_reflection(pattern: &Pattern) -> impl Iterator<Item = u32> + '_ {
+    (1..pattern.width())
+        .filter(|&i| {
+            let mut col1 = i;
+            let mut col0 = col1 - 1;
+
+            while zip(pattern.column(col0), pattern.column(col1)).all(|(a, b)| a == b) {
+                if col0 == 0 || col1 == pattern.width() - 1 {
+                    return true;
+                }
+
+                col0 -= 1;
+                col1 += 1;
+            }
+
+            false
+        })
+        .map(|i| i as u32)
+}
+
+fn alt_cols_left_of_reflection(pattern: &Pattern) -> Option<u32> {
+    let original_val = cols_left_of_reflection(pattern).next();
+
+    pattern.points().find_map(|point| {
+        let mut pattern = pattern.clone();
+        pattern[point] = !pattern[point];
+
+        let mut iter = cols_left_of_reflection(&pattern);
+
+        iter.find(|&val| Some(val) != original_val)
+    })
+}
+
+fn rows_above_reflection(pattern: &Pattern) -> impl Iterator<Item = u32> + '_ {
+    let row_len = pattern.height();
+
+    (1..row_len)
+        .filter(move |&i| {
+            let mut row1 = i;
+            let mut row0 = row1 - 1;
+
+            while pattern.row(row0) == pattern.row(row1) {
+                if row0 == 0 || row1 == row_len - 1 {
+                    return true;
+                }
+
+                row0 -= 1;
+                row1 += 1;
+            }
+
+            false
+        })
+        .map(|i| i as u32)
+}
+
+fn alt_rows_above_reflection(pattern: &Pattern) -> Option<u32> {
+    let original_val = rows_above_reflection(pattern).next();
+
+    pattern.points().find_map(|point| {
+        let mut pattern = pattern.clone();
+        pattern[point] = !pattern[point];
+
+        let mut iter = rows_above_reflection(&pattern);
+
+        iter.find(|&val| Some(val) != original_val)
+    })
+}
+
+pub type Pattern = Grid<bool>;
+
+/// Parses the patterns of ash and rocks
+pub fn parse_input(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    let mut lines = lines(input).peekable();
+    let mut patterns = Vec::new();
+
+    while lines.peek().is_some() {
+        patterns.push(Grid::parse_lines(
+            input,
+            &mut lines,
+            "`#` or `.`",
+            |char| match char {
+                '#' => Some(true),
+                '.' => Some(false),
+                _ => None,
+            },
+        )?);
+    }
+
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), Some(405));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Some(400));
+    }
+
+    #[test]
+    fn no_reflection() {
+        let patterns = parse_input("#..\n...\n..#\n").unwrap();
+
+        assert_eq!(part_one(&patterns), None);
+        assert_eq!(part_two(&patterns), Some(1));
+
+        // a single tile has nowhere for a line of reflection to go
+        let patterns = parse_input("#\n").unwrap();
+
+        assert_eq!(part_one(&patterns), None);
+        assert_eq!(part_two(&patterns), None);
+    }
+
+    #[test]
+    fn smudge_moves_reflection() {
+        let patterns = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(alt_rows_above_reflection(&patterns[0]), Some(3));
+        assert_eq!(alt_rows_above_reflection(&patterns[1]), Some(1));
+        assert_eq!(alt_cols_left_of_reflection(&patterns[1]), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("#.\n.x\n").err(),
+            Some(ParseError::new(2, 2, "`#` or `.`"))
+        );
+        assert_eq!(
+            parse_input("#.#\n##\n").err(),
+            Some(ParseError::new(2, 3, "3 columns"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day13>(0, &[EXAMPLE, &generate::<Day13>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day14;
+
+/// `size` is the side length of the platform, which is scattered with cube rocks and more round
+/// rocks.
+impl Generator for Day14 {
+    const DEFAULT_SIZE: usize = 100;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(match rng.index(100) {
+                    0..=9 => '#',
+                    10..=24 => 'O',
+                    _ => '.',
+                });
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
@@ -0,0 +1,170 @@
+mod generate;
+
+use aoc_common::{find_cycle, Dir, Grid, MaybeDisplay, ParseError, Point, Solution};
+
+const TOTAL_CYCLES: usize = 1000000000;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = (Grid<bool>, Vec<Point>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(&input.0, &input.1)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(&input.0, &input.1)
+    }
+}
+
+/// Returns the load on the north support beams after tilting the platform north
+pub fn part_one(grid: &Grid<bool>, rocks: &[Point]) -> u32 {
+    let mut grid = grid.clone();
+    let mut rocks = rocks.to_vec();
+
+    roll(&mut grid, &mut rocks, Dir::Up);
+    total_load(&grid, &rocks)
+}
+
+/// Returns the load on the north support beams after a billion spin cycles
+pub fn part_two(grid: &Grid<bool>, rocks: &[Point]) -> u32 {
+    let mut rocks = rocks.to_vec();
+    rocks.sort_unstable();
+
+    // the rocks are kept sorted so that equal platforms are equal states
+    let spins = find_cycle((grid.clone(), rocks), |(grid, rocks)| {
+        let mut grid = grid.clone();
+        let mut rocks = rocks.clone();
+
+        cycle(&mut grid, &mut rocks);
+        rocks.sort_unstable();
+
+        (grid, rocks)
+    });
+
+    let (grid, rocks) = spins.nth(TOTAL_CYCLES);
+    total_load(grid, rocks)
+}
+
+/// Rolls every rock as far as it will go in direction `dir`
+fn roll(grid: &mut Grid<bool>, rocks: &mut [Point], dir: Dir) {
+    // move the rocks furthest along first, so that they are out of the way of the others
+    let [dy, dx] = dir.offset();
+    rocks.sort_unstable_by_key(|rock| -(rock.y as isize * dy + rock.x as isize * dx));
+
+    for rock in rocks.iter_mut() {
+        while let Some(next) = grid.step(*rock, dir).filter(|&next| !grid[next]) {
+            grid[*rock] = false;
+            *rock = next;
+            grid[*rock] = true;
+        }
+    }
+}
+
+fn cycle(grid: &mut Grid<bool>, rocks: &mut [Point]) {
+    for dir in [Dir::Up, Dir::Left, Dir::Down, Dir::Right] {
+        roll(grid, rocks, dir);
+    }
+}
+
+fn total_load(grid: &Grid<bool>, rocks: &[Point]) -> u32 {
+    rocks
+        .iter()
+        .map(|rock| (grid.height() - rock.y) as u32)
+        .sum()
+}
+
+/// Parses the platform, returning which tiles hold any rock along with the positions of the
+/// round rocks
+pub fn parse_input(input: &str) -> Result<(Grid<bool>, Vec<Point>), ParseError> {
+    let tiles = Grid::parse(input, "`O`, `#` or `.`", |char| {
+        "O#.".contains(char).then_some(char)
+    })?;
+
+    let rocks = tiles
+        .points()
+        .filter(|&point| tiles[point] == 'O')
+        .collect();
+
+    Ok((tiles.map(|&tile| tile != '.'), rocks))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+    use proptest::{collection::vec, prelude::*};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        let (grid, rocks) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&grid, &rocks), 136);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let (grid, rocks) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_two(&grid, &rocks), 64);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("O.#\n.o.\n").err(),
+            Some(ParseError::new(2, 2, "`O`, `#` or `.`"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day14>(0, &[EXAMPLE, &generate::<Day14>(0, None)], 1000);
+    }
+
+    /// Spins the platform, remembering every arrangement of the rocks until one repeats
+    fn part_two_naive(grid: &Grid<bool>, rocks: &[Point]) -> u32 {
+        let mut grid = grid.clone();
+        let mut rocks = rocks.to_vec();
+        let mut seen: Vec<Vec<Point>> = Vec::new();
+
+        loop {
+            let mut sorted = rocks.clone();
+            sorted.sort_unstable();
+
+            if let Some(start) = seen.iter().position(|prev| *prev == sorted) {
+                let len = seen.len() - start;
+                let end = start + (TOTAL_CYCLES - start) % len;
+
+                return total_load(&grid, &seen[end]);
+            }
+
+            seen.push(sorted);
+            cycle(&mut grid, &mut rocks);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn part_two_matches_naive(
+            platform in (1..8usize).prop_flat_map(|width| vec(vec(0..3u8, width), 1..8)),
+        ) {
+            let text: String = platform
+                .iter()
+                .map(|row| {
+                    let row: String = row.iter().map(|&tile| ['O', '#', '.'][tile as usize]).collect();
+                    row + "\n"
+                })
+                .collect();
+
+            let (grid, rocks) = parse_input(&text).unwrap();
+            prop_assert_eq!(part_two(&grid, &rocks), part_two_naive(&grid, &rocks));
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day15;
+
+/// `size` is the number of steps, which reuse a pool of labels so that lenses are replaced and
+/// removed.
+impl Generator for Day15 {
+    const DEFAULT_SIZE: usize = 4000;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let labels: Vec<String> = (0..size / 8 + 1)
+            .map(|_| {
+                (0..rng.index(5) + 2)
+                    .map(|_| char::from(b'a' + rng.index(26) as u8))
+                    .collect()
+            })
+            .collect();
+
+        let steps: Vec<_> = (0..size)
+            .map(|_| {
+                let label = rng.choose(&labels);
+
+                if rng.chance(0.3) {
+                    format!("{label}-")
+                } else {
+                    format!("{label}={}", rng.range(1..=9))
+                }
+            })
+            .collect();
+
+        steps.join(",") + "\n"
+    }
+}
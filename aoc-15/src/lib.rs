@@ -0,0 +1,187 @@
+mod generate;
+
+use std::array::from_fn;
+
+use aoc_common::{lines, unexpected_end, MaybeDisplay, ParseError, Solution};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Step<'a>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the sum of the HASH of each step
+pub fn part_one(steps: &[Step]) -> u32 {
+    steps
+        .iter()
+        .map(|step| hash(step.text))
+        .map(u32::from)
+        .sum()
+}
+
+/// Returns the focusing power of the lenses after following every step
+pub fn part_two(steps: &[Step]) -> u32 {
+    let mut boxes: Boxes = from_fn(|_| Vec::new());
+
+    for step in steps {
+        let label = step.label;
+        let hash = hash(label);
+
+        match step.operation {
+            Operation::Remove => boxes[hash as usize].retain(|lens| lens.label != label),
+            Operation::Insert(focal_length) => {
+                if let Some(slot) = boxes[hash as usize]
+                    .iter_mut()
+                    .find(|lens| lens.label == label)
+                {
+                    slot.focal_length = focal_length;
+                } else {
+                    boxes[hash as usize].push(Slot {
+                        label,
+                        focal_length,
+                    });
+                }
+            }
+        }
+    }
+
+    focusing_power(&boxes)
+}
+
+pub struct Step<'a> {
+    text: &'a str,
+    label: &'a str,
+    operation: Operation,
+}
+
+enum Operation {
+    Remove,
+    Insert(u32),
+}
+
+type Boxes<'a> = [Vec<Slot<'a>>; 256];
+
+struct Slot<'a> {
+    label: &'a str,
+    focal_length: u32,
+}
+
+fn hash(sequence: &str) -> u8 {
+    sequence
+        .as_bytes()
+        .iter()
+        .fold(0_u8, |acc, &byte| acc.wrapping_add(byte).wrapping_mul(17))
+}
+
+fn focusing_power(boxes: &Boxes) -> u32 {
+    boxes
+        .iter()
+        .enumerate()
+        .flat_map(|(i, lenses)| {
+            let i = 1 + i as u32;
+
+            lenses
+                .iter()
+                .enumerate()
+                .map(move |(j, lens)| i * (j as u32 + 1) * lens.focal_length)
+        })
+        .sum()
+}
+
+/// Parses the steps of the initialization sequence
+pub fn parse_input(input: &str) -> Result<Vec<Step<'_>>, ParseError> {
+    let mut lines = lines(input);
+
+    let line = lines
+        .next()
+        .ok_or_else(|| unexpected_end(input, "a step"))?;
+
+    if let Some(line) = lines.find(|line| !line.text.trim().is_empty()) {
+        return Err(line.error_at(0, "the end of the input"));
+    }
+
+    line.text
+        .split(',')
+        .map(|text| {
+            let text = text.trim();
+
+            let (label, operation) = if let Some(label) = text.strip_suffix('-') {
+                (label, Operation::Remove)
+            } else {
+                let (label, focal_length) = line.split_once(text, "=")?;
+                let focal_length = line.parse(focal_length, "a focal length")?;
+                (label, Operation::Insert(focal_length))
+            };
+
+            if label.is_empty() || !label.bytes().all(|b| b.is_ascii_alphabetic()) {
+                return Err(line.error(text, "a label"));
+            }
+
+            Ok(Step {
+                text,
+                label,
+                operation,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 1320);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 145);
+    }
+
+    #[test]
+    fn hash_example() {
+        assert_eq!(hash("HASH"), 52);
+        assert_eq!(hash("rn=1"), 30);
+        assert_eq!(hash("rn"), 0);
+        assert_eq!(hash("qp"), 1);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("rn=1,cm-,qp=x\n").err(),
+            Some(ParseError::new(1, 13, "a focal length"))
+        );
+        assert_eq!(
+            parse_input("rn=1,c1-\n").err(),
+            Some(ParseError::new(1, 6, "a label"))
+        );
+        assert_eq!(
+            parse_input("rn=1\nab-\n").err(),
+            Some(ParseError::new(2, 1, "the end of the input"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day15>(0, &[EXAMPLE, &generate::<Day15>(0, None)], 1000);
+    }
+}
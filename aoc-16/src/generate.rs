@@ -0,0 +1,30 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day16;
+
+/// `size` is the side length of the contraption, which is sparsely scattered with mirrors and
+/// splitters.
+impl Generator for Day16 {
+    const DEFAULT_SIZE: usize = 110;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            for _ in 0..size {
+                let tile = if rng.chance(0.1) {
+                    *rng.choose(&['/', '\\', '-', '|'])
+                } else {
+                    '.'
+                };
+
+                input.push(tile);
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
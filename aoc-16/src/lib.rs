@@ -0,0 +1,194 @@
+mod generate;
+
+use std::iter;
+
+use aoc_common::{Dir, Grid, MaybeDisplay, ParseError, Point, Solution};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Grid<Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Parses the grid of mirrors and splitters
+pub fn parse_input(input: &str) -> Result<Grid<Tile>, ParseError> {
+    Grid::parse(input, "a mirror, splitter or `.`", |char| {
+        let ty = match char {
+            '/' => TileType::MirrorFS,
+            '\\' => TileType::MirrorBS,
+            '-' => TileType::SplitH,
+            '|' => TileType::SplitV,
+            '.' => TileType::Empty,
+            _ => return None,
+        };
+
+        Some(Tile {
+            ty,
+            visited_bitmask: 0,
+        })
+    })
+}
+
+/// Returns the number of tiles energized by a beam entering the top left tile heading right
+pub fn part_one(grid: &Grid<Tile>) -> u32 {
+    let mut grid = grid.clone();
+
+    let position = Position {
+        coords: Point::new(0, 0),
+        dir: Dir::Right,
+    };
+
+    propogate(&mut grid, &position);
+
+    grid.iter().filter(|tile| tile.visited_bitmask != 0).count() as u32
+}
+
+/// Returns the most tiles which can be energized by a beam entering from any edge
+pub fn part_two(grid: &Grid<Tile>) -> u32 {
+    let [bottom, right] = [grid.height() - 1, grid.width() - 1];
+
+    let left = (0..grid.height()).rev().map(|i| Position {
+        coords: Point::new(i, right),
+        dir: Dir::Left,
+    });
+
+    let right = (0..grid.height()).map(|i| Position {
+        coords: Point::new(i, 0),
+        dir: Dir::Right,
+    });
+
+    let up = (0..grid.width()).rev().map(|i| Position {
+        coords: Point::new(bottom, i),
+        dir: Dir::Up,
+    });
+
+    let down = (0..grid.width()).map(|i| Position {
+        coords: Point::new(0, i),
+        dir: Dir::Down,
+    });
+
+    left.chain(right)
+        .chain(up)
+        .chain(down)
+        .map(|position| {
+            let mut grid = grid.clone();
+
+            propogate(&mut grid, &position);
+
+            grid.iter().filter(|tile| tile.visited_bitmask != 0).count() as u32
+        })
+        .max()
+        .unwrap()
+}
+
+#[derive(Clone, Copy)]
+pub struct Tile {
+    ty: TileType,
+    /// Bit `dir as u8` is set once a beam has passed through heading in `dir`
+    visited_bitmask: u8,
+}
+
+#[derive(Clone, Copy)]
+enum TileType {
+    Empty,
+    MirrorFS,
+    MirrorBS,
+    SplitH,
+    SplitV,
+}
+
+#[derive(Clone, Copy)]
+struct Position {
+    coords: Point,
+    dir: Dir,
+}
+
+impl Position {
+    /// Returns the next position in the same direction, unless it's off the edge of `grid`
+    fn next(&self, grid: &Grid<Tile>) -> Option<Position> {
+        Some(Position {
+            coords: grid.step(self.coords, self.dir)?,
+            dir: self.dir,
+        })
+    }
+
+    fn turn(&self, dir: Dir) -> Self {
+        Self {
+            coords: self.coords,
+            dir,
+        }
+    }
+}
+
+fn propogate(grid: &mut Grid<Tile>, position: &Position) {
+    let tile = &mut grid[position.coords];
+    let bit = 1 << position.dir as u8;
+
+    if tile.visited_bitmask & bit > 0 {
+        // already visited
+        return;
+    }
+
+    tile.visited_bitmask |= bit;
+
+    let dir = position.dir;
+
+    // the beam leaves in the first direction, and also in the second if it's split
+    let (dir, split) = match tile.ty {
+        TileType::MirrorFS => (dir.reflect_slash(), None),
+        TileType::MirrorBS => (dir.reflect_backslash(), None),
+        TileType::SplitH if dir.is_vertical() => (dir.turn_left(), Some(dir.turn_right())),
+        TileType::SplitV if !dir.is_vertical() => (dir.turn_left(), Some(dir.turn_right())),
+        _ => (dir, None),
+    };
+
+    for dir in iter::once(dir).chain(split) {
+        if let Some(position) = position.turn(dir).next(grid) {
+            propogate(grid, &position);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 46);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 51);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("./\n.x\n").err(),
+            Some(ParseError::new(2, 2, "a mirror, splitter or `.`"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day16>(0, &[EXAMPLE, &generate::<Day16>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,23 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day17;
+
+/// `size` is the side length of the city, with a heat loss from 1 to 9 for each block.
+impl Generator for Day17 {
+    const DEFAULT_SIZE: usize = 141;
+    const MIN_SIZE: usize = 5;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut input = String::new();
+
+        for _ in 0..size {
+            for _ in 0..size {
+                input.push(char::from(b'1' + rng.index(9) as u8));
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
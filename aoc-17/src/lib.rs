@@ -0,0 +1,179 @@
+mod generate;
+
+use std::ops::RangeInclusive;
+
+use aoc_common::{dijkstra, Dir, Grid, MaybeAnswer, MaybeDisplay, ParseError, Point, Solution};
+
+/// Why there's no answer for a grid too small for the crucible to stop at the goal
+const NO_PATH: &str = "no path to the bottom right block";
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Grid<isize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_one(input), NO_PATH)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_two(input), NO_PATH)
+    }
+}
+
+/// A state of the crucible, which has moved `consecutive` blocks in direction `dir` to `coord`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct CoordEntry {
+    coord: Point,
+    dir: Dir,
+    consecutive: usize,
+}
+
+impl CoordEntry {
+    /// The crucible hasn't moved yet, so it can head off in any direction
+    fn start() -> [Self; 4] {
+        Dir::ALL.map(|dir| Self {
+            coord: Point::new(0, 0),
+            dir,
+            consecutive: 0,
+        })
+    }
+
+    /// Returns the entry after moving a block in direction `dir`, along with the heat lost
+    fn next(
+        &self,
+        dir: Dir,
+        turn_range: RangeInclusive<usize>,
+        grid: &Grid<isize>,
+    ) -> Option<(Self, isize)> {
+        if self.dir.reverse() == dir {
+            return None;
+        }
+
+        if self.dir != dir && !turn_range.contains(&self.consecutive) {
+            return None;
+        }
+
+        let consecutive = if self.dir == dir {
+            self.consecutive + 1
+        } else {
+            1
+        };
+
+        if consecutive > *turn_range.end() {
+            return None;
+        }
+
+        let coord = grid.step(self.coord, dir)?;
+
+        Some((
+            Self {
+                coord,
+                dir,
+                consecutive,
+            },
+            grid[coord],
+        ))
+    }
+}
+
+/// Parses the heat loss of each city block
+pub fn parse_input(input: &str) -> Result<Grid<isize>, ParseError> {
+    Grid::parse(input, "a heat loss digit", |char| {
+        char.to_digit(10).map(|digit| digit as isize)
+    })
+}
+
+/// Returns the least heat loss from the top left block to the bottom right block, moving at most
+/// three blocks before turning
+pub fn part_one(grid: &Grid<isize>) -> Option<isize> {
+    find_path(grid, 1..=3)
+}
+
+/// As [`part_one`], but the ultra crucible moves four to ten blocks before turning or stopping
+pub fn part_two(grid: &Grid<isize>) -> Option<isize> {
+    find_path(grid, 4..=10)
+}
+
+/// Returns the least heat loss from the top left block to the bottom right block, moving a
+/// number of blocks in `turn_range` between each turn. Returns `None` if the crucible can't stop
+/// there.
+pub fn find_path(grid: &Grid<isize>, turn_range: RangeInclusive<usize>) -> Option<isize> {
+    let goal = Point::new(grid.height() - 1, grid.width() - 1);
+
+    let path = dijkstra(
+        CoordEntry::start(),
+        |&entry| {
+            let turn_range = turn_range.clone();
+
+            Dir::ALL
+                .into_iter()
+                .filter_map(move |dir| entry.next(dir, turn_range.clone(), grid))
+        },
+        // the crucible can only stop once it's moved far enough to turn, unless it never moves
+        |entry| {
+            entry.coord == goal
+                && (entry.consecutive == 0 || turn_range.contains(&entry.consecutive))
+        },
+    );
+
+    path.map(|path| path.cost)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+    const EXAMPLE_TWO: &str = include_str!("../example2");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), Some(102));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Some(94));
+        assert_eq!(part_two(&parse_input(EXAMPLE_TWO).unwrap()), Some(71));
+    }
+
+    #[test]
+    fn first_run_counts_every_block() {
+        let grid = parse_input("11111111111\n").unwrap();
+
+        assert_eq!(part_two(&grid), Some(10));
+        assert_eq!(find_path(&grid, 1..=10), Some(10));
+    }
+
+    #[test]
+    fn too_small_for_ultra_crucible() {
+        let grid = parse_input("123\n456\n").unwrap();
+
+        assert_eq!(part_one(&grid), Some(11));
+        assert_eq!(part_two(&grid), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("24\n3a\n").err(),
+            Some(ParseError::new(2, 2, "a heat loss digit"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day17>(
+            0,
+            &[EXAMPLE, EXAMPLE_TWO, &generate::<Day17>(0, None)],
+            1000,
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use aoc_common::{random_loop, Generator, Rng};
+
+use crate::Day18;
+
+/// `size` is the number of rows and columns of the lattice the trench's corners are placed on.
+/// The lines of the lattice are spaced randomly, closely for the plan and far apart for the
+/// colors.
+impl Generator for Day18 {
+    const DEFAULT_SIZE: usize = 60;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(1);
+        let points = random_loop(rng, size, size, 0.5);
+
+        // keep only the corners, so every step turns
+        let corners: Vec<_> = (0..points.len())
+            .filter(|&i| {
+                let prev = points[(i + points.len() - 1) % points.len()];
+                let next = points[(i + 1) % points.len()];
+                prev[0] != next[0] && prev[1] != next[1]
+            })
+            .map(|i| points[i])
+            .collect();
+
+        // the distances in the colors have five hex digits, so must be below 16^5
+        let max_gap = (0xfffff / size as i64).max(1);
+
+        let plan = positions(rng, size, 8);
+        let colors = positions(rng, size, max_gap);
+
+        let mut input = String::new();
+
+        for (i, a) in corners.iter().enumerate() {
+            let b = corners[(i + 1) % corners.len()];
+
+            let (dir, digit) = match (b[0].cmp(&a[0]), b[1].cmp(&a[1])) {
+                (std::cmp::Ordering::Less, _) => ('U', 3),
+                (std::cmp::Ordering::Greater, _) => ('D', 1),
+                (_, std::cmp::Ordering::Less) => ('L', 2),
+                _ => ('R', 0),
+            };
+
+            let dist = |positions: &[[i64; 2]]| {
+                positions[b[0]][0].abs_diff(positions[a[0]][0])
+                    + positions[b[1]][1].abs_diff(positions[a[1]][1])
+            };
+
+            input.push_str(&format!(
+                "{dir} {} (#{:05x}{digit})\n",
+                dist(&plan),
+                dist(&colors)
+            ));
+        }
+
+        input
+    }
+}
+
+/// Returns the positions of the lines of the lattice, as `[row, column]` pairs, with gaps of up
+/// to `max_gap` between them.
+fn positions(rng: &mut Rng, size: usize, max_gap: i64) -> Vec<[i64; 2]> {
+    let mut position = [0, 0];
+
+    (0..=size)
+        .map(|_| {
+            let current = position;
+            position = position.map(|p| p + rng.range(1..=max_gap));
+            current
+        })
+        .collect()
+}
@@ -0,0 +1,157 @@
+mod generate;
+
+use aoc_common::{lines, Dir, MaybeDisplay, ParseError, Solution};
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+/// Returns the cubic meters of lava the lagoon can hold
+pub fn part_one(plan: &[Instruction]) -> i128 {
+    lagoon_volume(plan.iter().map(|instruction| instruction.step))
+}
+
+/// As [`part_one`], but the direction and distance of each step are taken from its color
+pub fn part_two(plan: &[Instruction]) -> i128 {
+    lagoon_volume(plan.iter().map(|instruction| instruction.color_step))
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Step {
+    dir: Dir,
+    dist: u32,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    step: Step,
+    /// The step encoded in the hex color code
+    color_step: Step,
+}
+
+/// Returns the number of cubes dug out by following `steps`, including the trench itself.
+///
+/// Uses the shoelace formula for the area, then Pick's theorem to count the interior cubes. The
+/// sums are widened to `i128`, so the products of even the longest `u32` distances fit.
+fn lagoon_volume(steps: impl Iterator<Item = Step>) -> i128 {
+    let mut coord = [0i128, 0];
+    let mut double_area = 0;
+    let mut perimeter = 0;
+
+    for step in steps {
+        let dist = i128::from(step.dist);
+        let offset = step.dir.offset().map(|c| c as i128);
+        let next = [coord[0] + offset[0] * dist, coord[1] + offset[1] * dist];
+
+        double_area += coord[0] * next[1] - next[0] * coord[1];
+        perimeter += dist;
+
+        coord = next;
+    }
+
+    double_area.abs() / 2 + perimeter / 2 + 1
+}
+
+/// Parses the dig plan
+pub fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let mut iter = line.text.split_whitespace();
+
+            let token = line.next(&mut iter, "a direction")?;
+            let dir = match token {
+                "U" => Dir::Up,
+                "D" => Dir::Down,
+                "L" => Dir::Left,
+                "R" => Dir::Right,
+                _ => return Err(line.error(token, "`U`, `D`, `L` or `R`")),
+            };
+
+            let dist = line.parse_next(&mut iter, "a distance")?;
+
+            let token = line.next(&mut iter, "a color")?;
+            let color = line.strip_prefix(token, "(#")?;
+            let (color, _) = line.split_once(color, ")")?;
+
+            if color.len() != 6 || !color.bytes().all(|b| b.is_ascii_hexdigit()) {
+                return Err(line.error(color, "a 6 digit hex color"));
+            }
+
+            let color_dir = match &color[5..] {
+                "0" => Dir::Right,
+                "1" => Dir::Down,
+                "2" => Dir::Left,
+                "3" => Dir::Up,
+                _ => return Err(line.error(&color[5..], "a direction digit from 0 to 3")),
+            };
+
+            let color_dist = u32::from_str_radix(&color[..5], 16)
+                .map_err(|_| line.error(color, "a hex distance"))?;
+
+            Ok(Instruction {
+                step: Step { dir, dist },
+                color_step: Step {
+                    dir: color_dir,
+                    dist: color_dist,
+                },
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 62);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 952408144115);
+    }
+
+    #[test]
+    fn distances_are_unsigned() {
+        assert_eq!(
+            parse_input("R -6 (#70c710)\n").err(),
+            Some(ParseError::new(1, 3, "a distance"))
+        );
+        assert_eq!(
+            parse_input("R 9223372036854775807 (#70c710)\n").err(),
+            Some(ParseError::new(1, 3, "a distance"))
+        );
+
+        let max = u32::MAX;
+        let plan =
+            format!("R {max} (#000000)\nD {max} (#000000)\nL {max} (#000000)\nU {max} (#000000)\n");
+        let side = i128::from(max) + 1;
+
+        assert_eq!(part_one(&parse_input(&plan).unwrap()), side * side);
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day18>(0, &[EXAMPLE, &generate::<Day18>(0, None)], 1000);
+    }
+}
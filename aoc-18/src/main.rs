@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_18::Day18;
+
+fn main() -> ExitCode {
+    aoc_common::day_main::<Day18>()
+}
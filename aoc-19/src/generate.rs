@@ -0,0 +1,80 @@
+use std::collections::{HashSet, VecDeque};
+
+use aoc_common::{Generator, Rng};
+
+use crate::Day19;
+
+/// `size` is the number of workflows, which form a tree rooted at `in`. There are half as many
+/// parts as workflows.
+impl Generator for Day19 {
+    const DEFAULT_SIZE: usize = 550;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut tree = Tree {
+            labels: HashSet::from(["in".to_owned()]),
+            unwritten: VecDeque::from(["in".to_owned()]),
+            remaining: size.max(1) - 1,
+        };
+
+        let mut workflows = Vec::new();
+
+        while let Some(label) = tree.unwritten.pop_front() {
+            let mut rules: Vec<_> = (0..rng.index(3) + 1)
+                .map(|_| {
+                    let rating = *rng.choose(&['x', 'm', 'a', 's']);
+                    let ordering = *rng.choose(&['<', '>']);
+                    let value = rng.range(2..=3999);
+
+                    format!("{rating}{ordering}{value}:{}", tree.dst(rng))
+                })
+                .collect();
+
+            rules.push(tree.dst(rng));
+
+            workflows.push(format!("{label}{{{}}}", rules.join(",")));
+        }
+
+        rng.shuffle(&mut workflows);
+
+        let parts: Vec<_> = (0..size / 2 + 1)
+            .map(|_| {
+                let [x, m, a, s] = [(); 4].map(|()| rng.range(1..=4000));
+                format!("{{x={x},m={m},a={a},s={s}}}")
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"))
+    }
+}
+
+/// The workflows generated so far
+struct Tree {
+    labels: HashSet<String>,
+    /// Labels which have been jumped to, but whose workflows haven't been written yet
+    unwritten: VecDeque<String>,
+    /// The number of workflows left to add
+    remaining: usize,
+}
+
+impl Tree {
+    /// Returns the destination of a rule, which is a new workflow while there are any left
+    fn dst(&mut self, rng: &mut Rng) -> String {
+        if self.remaining == 0 || rng.chance(0.4) {
+            return rng.choose(&["A", "R"]).to_string();
+        }
+
+        self.remaining -= 1;
+
+        loop {
+            let label: String = (0..rng.index(2) + 2)
+                .map(|_| char::from(b'a' + rng.index(26) as u8))
+                .collect();
+
+            if self.labels.insert(label.clone()) {
+                self.unwritten.push_back(label.clone());
+                return label;
+            }
+        }
+    }
+}
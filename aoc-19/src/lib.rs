@@ -0,0 +1,456 @@
+mod generate;
+
+use std::{
+    array::from_fn,
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use aoc_common::{lines, unexpected_end, Line, MaybeDisplay, ParseError, Solution};
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Workflows<'a>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(&input.1, &input.0)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(&input.0)
+    }
+}
+
+pub type Workflows<'a> = HashMap<&'a str, Workflow<'a>>;
+
+#[derive(Debug)]
+pub struct Workflow<'a> {
+    instructions: Vec<Instruction<'a>>,
+}
+
+#[derive(Debug)]
+struct Instruction<'a> {
+    op: Option<Operation>,
+    dst: Dst<'a>,
+}
+
+#[derive(Debug)]
+struct Operation {
+    ordering: Ordering,
+    part_index: usize,
+    val: u32,
+}
+
+#[derive(Debug)]
+enum Dst<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+pub type Part = [u32; 4];
+
+pub type PartRange = [(u32, u32); 4];
+
+/// Parses the workflows, keyed by label, and the ratings of each part
+pub fn parse_input(input: &str) -> Result<(Workflows<'_>, Vec<Part>), ParseError> {
+    let mut lines = lines(input);
+
+    let mut workflows = HashMap::new();
+    // labels of the workflows which are jumped to, with an error for where they're missing
+    let mut references = vec![("in", unexpected_end(input, "a workflow labelled `in`"))];
+    // the workflows each workflow jumps to, with an error for where they loop back
+    let mut jumps: HashMap<_, Vec<_>> = HashMap::new();
+
+    loop {
+        let line = lines
+            .next()
+            .ok_or_else(|| unexpected_end(input, "an empty line"))?;
+
+        if line.text.is_empty() {
+            break;
+        }
+
+        let (label, rest) = line.split_once(line.text, "{")?;
+        let (rest, end) = line.split_once(rest, "}")?;
+
+        if label.is_empty() {
+            return Err(line.error_at(0, "a workflow label"));
+        }
+
+        if !end.is_empty() {
+            return Err(line.error(end, "the end of the line"));
+        }
+
+        let mut instructions = Vec::new();
+
+        for str in rest.split(',') {
+            let instruction = parse_instruction(line, str)?;
+
+            if let Dst::Workflow(dst) = instruction.dst {
+                references.push((dst, line.error(dst, "a known workflow")));
+                jumps
+                    .entry(label)
+                    .or_default()
+                    .push((dst, line.error(dst, "a workflow which doesn't loop")));
+            }
+
+            instructions.push(instruction);
+        }
+
+        if instructions.last().is_some_and(|last| last.op.is_some()) {
+            return Err(line.error(end, "an unconditional destination"));
+        }
+
+        if workflows.insert(label, Workflow { instructions }).is_some() {
+            return Err(line.error_at(0, "a new workflow label"));
+        }
+    }
+
+    if let Some((_, err)) = references
+        .into_iter()
+        .find(|(label, _)| !workflows.contains_key(label))
+    {
+        return Err(err);
+    }
+
+    if let Some(err) = find_loop("in", &jumps, &mut HashSet::new(), &mut HashSet::new()) {
+        return Err(err);
+    }
+
+    let parts = lines
+        .map(|line| {
+            let mut rest = line.strip_prefix(line.text, "{")?;
+            let mut part = [0; 4];
+
+            for (i, (rating, name)) in part.iter_mut().zip(["x=", "m=", "a=", "s="]).enumerate() {
+                if i > 0 {
+                    rest = line.strip_prefix(rest, ",")?;
+                }
+
+                rest = line.strip_prefix(rest, name)?;
+
+                let end = rest
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len());
+                *rating = line.parse(&rest[..end], "a rating")?;
+                rest = &rest[end..];
+            }
+
+            if line.strip_prefix(rest, "}")?.is_empty() {
+                Ok(part)
+            } else {
+                Err(line.error_after(rest, "the end of the line"))
+            }
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((workflows, parts))
+}
+
+/// Returns the error for the first jump from `label` or a workflow it leads to which loops back to
+/// a workflow in `following`, skipping the workflows in `checked`
+fn find_loop<'a>(
+    label: &'a str,
+    jumps: &HashMap<&'a str, Vec<(&'a str, ParseError)>>,
+    following: &mut HashSet<&'a str>,
+    checked: &mut HashSet<&'a str>,
+) -> Option<ParseError> {
+    if !checked.insert(label) {
+        return None;
+    }
+
+    following.insert(label);
+
+    for (dst, err) in jumps.get(label).into_iter().flatten() {
+        if following.contains(dst) {
+            return Some(err.clone());
+        }
+
+        if let Some(err) = find_loop(dst, jumps, following, checked) {
+            return Some(err);
+        }
+    }
+
+    following.remove(label);
+    None
+}
+
+fn parse_instruction<'a>(line: Line<'a>, str: &'a str) -> Result<Instruction<'a>, ParseError> {
+    let Some((op, dst)) = str.split_once(':') else {
+        return Ok(Instruction {
+            op: None,
+            dst: map_dst(line, str)?,
+        });
+    };
+
+    let (ordering, (part_type, val)) = if let Some(split) = op.split_once('<') {
+        (Ordering::Less, split)
+    } else {
+        (Ordering::Greater, line.split_once(op, ">")?)
+    };
+
+    let part_index = match part_type {
+        "x" => 0,
+        "m" => 1,
+        "a" => 2,
+        "s" => 3,
+        _ => return Err(line.error(part_type, "`x`, `m`, `a` or `s`")),
+    };
+
+    Ok(Instruction {
+        op: Some(Operation {
+            ordering,
+            part_index,
+            val: line.parse(val, "a rating")?,
+        }),
+        dst: map_dst(line, dst)?,
+    })
+}
+
+fn map_dst<'a>(line: Line<'a>, dst: &'a str) -> Result<Dst<'a>, ParseError> {
+    match dst {
+        "A" => Ok(Dst::Accept),
+        "R" => Ok(Dst::Reject),
+        "" => Err(line.error(dst, "a destination")),
+        label => Ok(Dst::Workflow(label)),
+    }
+}
+
+/// Returns the sum of the ratings of every part the workflows accept
+pub fn part_one(parts: &[Part], workflows: &Workflows) -> u32 {
+    parts
+        .iter()
+        .filter(|part| accepted(part, workflows))
+        .flatten()
+        .sum()
+}
+
+fn accepted(part: &Part, workflows: &Workflows) -> bool {
+    accepted_recursive(part, &workflows["in"], workflows)
+}
+
+fn accepted_recursive(part: &Part, workflow: &Workflow, workflows: &Workflows) -> bool {
+    for instruction in &workflow.instructions {
+        if let Some(op) = &instruction.op {
+            if part[op.part_index].cmp(&op.val) == op.ordering {
+                return goto_dst(part, &instruction.dst, workflows);
+            }
+        } else {
+            return goto_dst(part, &instruction.dst, workflows);
+        }
+    }
+
+    unreachable!()
+}
+
+fn goto_dst(part: &Part, dst: &Dst, workflows: &Workflows) -> bool {
+    match dst {
+        Dst::Accept => true,
+        Dst::Reject => false,
+        Dst::Workflow(dst) => accepted_recursive(part, &workflows[dst], workflows),
+    }
+}
+
+/// Returns the number of combinations of ratings from 1 to 4000 the workflows accept
+pub fn part_two(workflows: &Workflows) -> u64 {
+    let mut accepted_ranges = HashSet::new();
+
+    calc_accepted_ranges(
+        from_fn(|_| (1, 4000)),
+        &workflows["in"],
+        workflows,
+        &mut accepted_ranges,
+    );
+
+    accepted_ranges
+        .into_iter()
+        .map(|range| {
+            range
+                .map(|a| (a.1 + 1 - a.0) as u64)
+                .into_iter()
+                .reduce(|acc, a| acc * a)
+                .unwrap()
+        })
+        .sum()
+}
+
+/// Adds the ranges of ratings within `part_range` which `workflow` accepts to `accepted_ranges`
+pub fn calc_accepted_ranges(
+    mut part_range: PartRange,
+    workflow: &Workflow,
+    workflows: &Workflows,
+    accepted_ranges: &mut HashSet<PartRange>,
+) {
+    for instruction in &workflow.instructions {
+        if let Some(op) = &instruction.op {
+            let mut part_range_branch = part_range;
+            let (range, branch) = (
+                &mut part_range[op.part_index],
+                &mut part_range_branch[op.part_index],
+            );
+
+            // narrow the ranges rather than overwrite them, as earlier conditions may have
+            // already narrowed them further
+            if op.ordering == Ordering::Less {
+                branch.1 = branch.1.min(op.val.saturating_sub(1));
+                range.0 = range.0.max(op.val);
+            } else {
+                range.1 = range.1.min(op.val);
+                branch.0 = branch.0.max(op.val.saturating_add(1));
+            }
+
+            branch_range(
+                part_range_branch,
+                &instruction.dst,
+                workflows,
+                accepted_ranges,
+            );
+        } else {
+            branch_range(part_range, &instruction.dst, workflows, accepted_ranges);
+        }
+    }
+}
+
+fn branch_range(
+    part_range: PartRange,
+    dst: &Dst,
+    workflows: &Workflows,
+    accepted_ranges: &mut HashSet<PartRange>,
+) {
+    // conditions can contradict earlier ones, leaving no parts to branch with
+    if part_range.iter().any(|(min, max)| min > max) {
+        return;
+    }
+
+    match dst {
+        Dst::Accept => {
+            accepted_ranges.insert(part_range);
+        }
+        Dst::Workflow(dst) => {
+            calc_accepted_ranges(part_range, &workflows[dst], workflows, accepted_ranges);
+        }
+        Dst::Reject => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        let (workflows, parts) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_one(&parts, &workflows), 19114);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let (workflows, _) = parse_input(EXAMPLE).unwrap();
+        assert_eq!(part_two(&workflows), 167409079868000);
+    }
+
+    #[test]
+    fn part_two_later_conditions_narrow_ranges() {
+        // `x>220` can only match parts which already failed `x<2644`
+        let input = "in{x<2644:th,x>3588:A,x>220:A,R}\nth{m>275:R,m>212:A,A}\n\n";
+        let (workflows, _) = parse_input(input).unwrap();
+
+        assert_eq!(part_two(&workflows), 98477200000000);
+    }
+
+    #[test]
+    fn loops_are_rejected() {
+        assert_eq!(
+            parse_input("in{a<5:in,A}\n\n").err(),
+            Some(ParseError::new(1, 8, "a workflow which doesn't loop"))
+        );
+        assert_eq!(
+            parse_input("in{ab}\nab{m>1:A,cd}\ncd{x<2:R,ab}\n\n").err(),
+            Some(ParseError::new(3, 10, "a workflow which doesn't loop"))
+        );
+        // jumping to the same workflow twice without looping is fine
+        assert!(parse_input("in{a<5:ab,ab}\nab{A}\n\n").is_ok());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("in{a<5:,A}\n\n").err(),
+            Some(ParseError::new(1, 8, "a destination"))
+        );
+        assert_eq!(
+            parse_input("in{a<5:ab,A}\n\n").err(),
+            Some(ParseError::new(1, 8, "a known workflow"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day19>(0, &[EXAMPLE, &generate::<Day19>(0, None)], 1000);
+    }
+
+    /// Splits each rating at every value the workflows compare it with, then checks one part from
+    /// each block of ratings which are all treated the same
+    fn part_two_naive(workflows: &Workflows) -> u64 {
+        let starts: [Vec<u32>; 4] = from_fn(|part_index| {
+            let mut starts: Vec<_> = workflows
+                .values()
+                .flat_map(|workflow| &workflow.instructions)
+                .filter_map(|instruction| instruction.op.as_ref())
+                .filter(|op| op.part_index == part_index)
+                .map(|op| match op.ordering {
+                    Ordering::Less => op.val,
+                    _ => op.val + 1,
+                })
+                .chain([1, 4001])
+                .filter(|start| (1..=4001).contains(start))
+                .collect();
+
+            starts.sort_unstable();
+            starts.dedup();
+            starts
+        });
+
+        let mut total = 0;
+
+        for x in starts[0].windows(2) {
+            for m in starts[1].windows(2) {
+                for a in starts[2].windows(2) {
+                    for s in starts[3].windows(2) {
+                        let blocks = [x, m, a, s];
+
+                        if accepted(&blocks.map(|block| block[0]), workflows) {
+                            total += blocks
+                                .iter()
+                                .map(|block| (block[1] - block[0]) as u64)
+                                .product::<u64>();
+                        }
+                    }
+                }
+            }
+        }
+
+        total
+    }
+
+    proptest! {
+        #[test]
+        fn part_two_matches_naive(seed: u64, size in 1..8usize) {
+            let input = generate::<Day19>(seed, Some(size));
+            let (workflows, _) = parse_input(&input).unwrap();
+
+            prop_assert_eq!(part_two(&workflows), part_two_naive(&workflows));
+        }
+    }
+}
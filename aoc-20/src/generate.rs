@@ -0,0 +1,92 @@
+use aoc_common::{Generator, Rng};
+use num::integer::lcm;
+
+use crate::Day20;
+
+/// The number of counters feeding `rx`, as in real inputs
+const COUNTERS: usize = 4;
+
+/// `size` is the number of bits in each counter, from 2 to 20. Each counter is a chain of
+/// flip-flops which resets itself through a conjunction after a random odd number of presses,
+/// at which point it sends a high pulse towards `rx`.
+impl Generator for Day20 {
+    const DEFAULT_SIZE: usize = 12;
+    const MIN_SIZE: usize = 2;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_with_period(rng, size).0
+    }
+}
+
+/// Returns the input along with the number of presses before `rx` is sent a low pulse
+fn generate_with_period(rng: &mut Rng, size: usize) -> (String, u64) {
+    let bits = size.clamp(2, 20);
+
+    let mut labels: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| String::from_utf8(vec![a, b]).unwrap()))
+        .filter(|label| label != "rx")
+        .collect();
+    rng.shuffle(&mut labels);
+
+    let mut labels = labels.into_iter();
+    let mut label = || labels.next().unwrap();
+
+    let output = label();
+    let mut modules = vec![format!("&{output} -> rx")];
+    let mut firsts = Vec::new();
+    let mut presses = 1;
+
+    for _ in 0..COUNTERS {
+        let period = (1 << (bits - 1)) | rng.below(1 << (bits - 1)) | 1;
+        presses = lcm(presses, period);
+
+        let flip_flops: Vec<_> = (0..bits).map(|_| label()).collect();
+        let hub = label();
+        let inverter = label();
+
+        // the hub resets the counter by sending a low pulse to the flip-flops which are off once
+        // it reaches the period, along with the first, which carries into all the others
+        let mut resets = vec![flip_flops[0].clone()];
+
+        for (i, flip_flop) in flip_flops.iter().enumerate() {
+            let mut outputs: Vec<_> = flip_flops.get(i + 1).into_iter().cloned().collect();
+
+            if period & (1 << i) != 0 {
+                outputs.push(hub.clone());
+            } else {
+                resets.push(flip_flop.clone());
+            }
+
+            modules.push(format!("%{flip_flop} -> {}", outputs.join(", ")));
+        }
+
+        resets.push(inverter.clone());
+
+        modules.push(format!("&{hub} -> {}", resets.join(", ")));
+        modules.push(format!("&{inverter} -> {output}"));
+
+        firsts.push(flip_flops[0].clone());
+    }
+
+    modules.push(format!("broadcaster -> {}", firsts.join(", ")));
+    rng.shuffle(&mut modules);
+
+    (modules.join("\n") + "\n", presses)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Part};
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..10 {
+            let (input, presses) = generate_with_period(&mut Rng::new(seed), 8);
+            let run = solve::<Day20>(&input, &Part::ALL).unwrap();
+
+            assert_eq!(run.answers[1].value, Some(presses.to_string()));
+        }
+    }
+}
@@ -0,0 +1,288 @@
+mod generate;
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{lines, Line, MaybeAnswer, MaybeDisplay, ParseError, Solution};
+use num::integer::lcm;
+
+/// The most flip-flops in a counter feeding `rx` which part two waits for. Real inputs have 12,
+/// and generated ones up to 20.
+const MAX_COUNTER_BITS: usize = 20;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Modules<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_two(input), "no conjunction of counters feeding `rx`")
+    }
+}
+
+pub type Modules<'a> = HashMap<&'a str, Module<'a>>;
+
+#[derive(Clone, Debug)]
+pub struct Module<'a> {
+    ty: ModuleType<'a>,
+    outputs: Vec<&'a str>,
+}
+
+#[derive(Clone, Debug)]
+enum ModuleType<'a> {
+    Broadcaster,
+    /// Holds whether the flip-flop is on
+    FlipFlop(bool),
+    /// Holds the most recent pulse received from each input
+    Conjunction(HashMap<&'a str, Pulse>),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Pulse {
+    Low,
+    High,
+}
+
+/// A pulse in flight, as `(source, destination, pulse)`
+type Signal<'a> = (&'a str, &'a str, Pulse);
+
+/// Returns the product of the number of low and high pulses sent when pushing the button 1000
+/// times
+pub fn part_one(modules: &Modules) -> u64 {
+    let mut modules = modules.clone();
+    let mut counts = [0, 0];
+
+    for _ in 0..1000 {
+        press_button(&mut modules, |(_, _, pulse)| match pulse {
+            Pulse::Low => counts[0] += 1,
+            Pulse::High => counts[1] += 1,
+        });
+    }
+
+    counts[0] * counts[1]
+}
+
+/// Returns the fewest button pushes which send a single low pulse to `rx`, or `None` if `rx`
+/// isn't fed by a conjunction whose inputs all send it high pulses
+pub fn part_two(modules: &Modules) -> Option<u64> {
+    let mut modules = modules.clone();
+
+    // rx is fed by a single conjunction, which only sends a low pulse once all of its inputs have
+    // most recently sent it a high pulse
+    let (&feeder, _) = modules
+        .iter()
+        .find(|(_, module)| module.outputs.contains(&"rx"))?;
+
+    let ModuleType::Conjunction(inputs) = &modules[feeder].ty else {
+        return None;
+    };
+
+    let mut periods: HashMap<&str, Option<u64>> =
+        inputs.keys().map(|&input| (input, None)).collect();
+
+    // each input is the end of a counter, which can't count past 2^flip-flops presses, so an input
+    // which hasn't sent a high pulse by then never will
+    let flip_flops = modules
+        .values()
+        .filter(|module| matches!(module.ty, ModuleType::FlipFlop(_)))
+        .count();
+    let max_presses = 1 << flip_flops.min(MAX_COUNTER_BITS);
+
+    let mut presses = 0;
+
+    while periods.values().any(Option::is_none) {
+        if presses == max_presses {
+            return None;
+        }
+
+        presses += 1;
+
+        press_button(&mut modules, |(src, dst, pulse)| {
+            if dst == feeder && pulse == Pulse::High {
+                periods.get_mut(src).unwrap().get_or_insert(presses);
+            }
+        });
+    }
+
+    periods.into_values().flatten().reduce(lcm)
+}
+
+/// Sends a low pulse to the broadcaster and processes pulses in the order they are sent until the
+/// network settles. `on_signal` is called for every pulse sent, including the initial one.
+fn press_button<'a, F>(modules: &mut Modules<'a>, mut on_signal: F)
+where
+    F: FnMut(Signal<'a>),
+{
+    let mut queue = VecDeque::from([("button", "broadcaster", Pulse::Low)]);
+
+    while let Some(signal @ (src, dst, pulse)) = queue.pop_front() {
+        on_signal(signal);
+
+        let Some(module) = modules.get_mut(dst) else {
+            // untyped module, e.g. rx
+            continue;
+        };
+
+        let output = match &mut module.ty {
+            ModuleType::Broadcaster => pulse,
+            ModuleType::FlipFlop(on) => {
+                if pulse == Pulse::High {
+                    continue;
+                }
+
+                *on = !*on;
+
+                if *on {
+                    Pulse::High
+                } else {
+                    Pulse::Low
+                }
+            }
+            ModuleType::Conjunction(memory) => {
+                memory.insert(src, pulse);
+
+                if memory.values().all(|&pulse| pulse == Pulse::High) {
+                    Pulse::Low
+                } else {
+                    Pulse::High
+                }
+            }
+        };
+
+        queue.extend(
+            module
+                .outputs
+                .iter()
+                .map(|&output_dst| (dst, output_dst, output)),
+        );
+    }
+}
+
+/// Parses the modules, keyed by name
+pub fn parse_input(input: &str) -> Result<Modules<'_>, ParseError> {
+    let mut modules = HashMap::new();
+
+    for line in lines(input) {
+        let (label, outputs) = line.split_once(line.text, " -> ")?;
+
+        let outputs = outputs
+            .split(", ")
+            .map(|output| module_label(line, output))
+            .collect::<Result<_, _>>()?;
+
+        let (label, ty) = if let Some(label) = label.strip_prefix('%') {
+            (label, ModuleType::FlipFlop(false))
+        } else if let Some(label) = label.strip_prefix('&') {
+            (label, ModuleType::Conjunction(HashMap::new()))
+        } else if label == "broadcaster" {
+            (label, ModuleType::Broadcaster)
+        } else {
+            return Err(line.error(label, "`%`, `&` or `broadcaster`"));
+        };
+
+        let label = module_label(line, label)?;
+
+        if modules.insert(label, Module { ty, outputs }).is_some() {
+            return Err(line.error(label, "a new module label"));
+        }
+    }
+
+    // conjunctions initially remember a low pulse for each of their inputs
+    let connections: Vec<_> = modules
+        .iter()
+        .flat_map(|(&src, module)| module.outputs.iter().map(move |&dst| (src, dst)))
+        .collect();
+
+    for (src, dst) in connections {
+        if let Some(Module {
+            ty: ModuleType::Conjunction(memory),
+            ..
+        }) = modules.get_mut(dst)
+        {
+            memory.insert(src, Pulse::Low);
+        }
+    }
+
+    Ok(modules)
+}
+
+fn module_label<'a>(line: Line<'a>, label: &'a str) -> Result<&'a str, ParseError> {
+    if !label.is_empty() && label.bytes().all(|b| b.is_ascii_alphabetic()) {
+        Ok(label)
+    } else {
+        Err(line.error(label, "a module label"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+    const EXAMPLE_TWO: &str = include_str!("../example2");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 32000000);
+        assert_eq!(part_one(&parse_input(EXAMPLE_TWO).unwrap()), 11687500);
+    }
+
+    #[test]
+    fn part_two_without_rx() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), None);
+        assert_eq!(part_two(&parse_input("").unwrap()), None);
+        assert_eq!(
+            part_two(&parse_input("broadcaster -> a\n%a -> rx\n").unwrap()),
+            None
+        );
+        assert_eq!(
+            Day20::part_two(&parse_input("").unwrap()).display(),
+            Err("no conjunction of counters feeding `rx`")
+        );
+    }
+
+    #[test]
+    fn part_two_without_high_pulses() {
+        // `d` is never sent a pulse
+        let unreachable = "broadcaster -> a\n&c -> rx\n%d -> c\n";
+        assert_eq!(part_two(&parse_input(unreachable).unwrap()), None);
+
+        // `y` is only ever sent high pulses, so never sends one itself
+        let ignored = "broadcaster -> x\n&x -> y\n%y -> c\n&c -> rx\n";
+        assert_eq!(part_two(&parse_input(ignored).unwrap()), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("a -> b\n").err(),
+            Some(ParseError::new(1, 1, "`%`, `&` or `broadcaster`"))
+        );
+        assert_eq!(
+            parse_input("broadcaster -> a\n%a -> b1\n").err(),
+            Some(ParseError::new(2, 7, "a module label"))
+        );
+        assert_eq!(
+            parse_input("%a -> b\n%a -> c\n").err(),
+            Some(ParseError::new(2, 2, "a new module label"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day20>(
+            0,
+            &[EXAMPLE, EXAMPLE_TWO, &generate::<Day20>(0, None)],
+            1000,
+        );
+    }
+}
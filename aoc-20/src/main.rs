@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_20::Day20;
+
+fn main() -> ExitCode {
+    aoc_common::day_main::<Day20>()
+}
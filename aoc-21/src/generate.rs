@@ -0,0 +1,42 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day21;
+
+/// `size` is the side length of the garden, which is made odd so the start is in the middle.
+/// Like real inputs, the start's row and column, the edges and a diamond through the middle of
+/// each edge are free of rocks, which lets part two extrapolate.
+impl Generator for Day21 {
+    const DEFAULT_SIZE: usize = 131;
+    const MIN_SIZE: usize = 3;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let size = size.max(3) | 1;
+        let mid = size / 2;
+
+        let mut input = String::new();
+
+        for y in 0..size {
+            for x in 0..size {
+                let clear = y == mid
+                    || x == mid
+                    || y == 0
+                    || x == 0
+                    || y == size - 1
+                    || x == size - 1
+                    || (y.abs_diff(mid) + x.abs_diff(mid)).abs_diff(mid) <= 1;
+
+                input.push(if y == mid && x == mid {
+                    'S'
+                } else if !clear && rng.chance(0.12) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+
+            input.push('\n');
+        }
+
+        input
+    }
+}
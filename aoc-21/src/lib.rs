@@ -0,0 +1,266 @@
+mod generate;
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{
+    unexpected_end, Dir, Grid, MaybeAnswer, MaybeDisplay, ParseError, Point, Solution,
+};
+
+/// A `[row, column]` coord, which may be outside the map when it's tiled
+type Coord = [isize; 2];
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(
+            part_two(input),
+            "the reachable plots don't grow quadratically",
+        )
+    }
+}
+
+/// Returns the number of garden plots reachable in exactly 64 steps
+pub fn part_one(garden: &Garden) -> u64 {
+    reachable(garden, 64, false)
+}
+
+/// Returns the number of garden plots reachable in exactly 26501365 steps, where the map repeats
+/// infinitely in every direction
+pub fn part_two(garden: &Garden) -> Option<u64> {
+    extrapolate(garden, 26501365)
+}
+
+pub struct Garden {
+    /// Whether each tile of the map, which is square, is a rock
+    rocks: Grid<bool>,
+    start: Coord,
+}
+
+impl Garden {
+    fn len(&self) -> usize {
+        self.rocks.width()
+    }
+
+    /// Returns true if `coord` is a garden plot. When `tiled`, the map repeats infinitely in every
+    /// direction, otherwise coords outside of it are never plots.
+    fn is_plot(&self, coord: Coord, tiled: bool) -> bool {
+        let len = self.len() as isize;
+
+        if !tiled && coord.iter().any(|c| !(0..len).contains(c)) {
+            return false;
+        }
+
+        let [y, x] = coord.map(|c| c.rem_euclid(len) as usize);
+
+        !self.rocks[Point::new(y, x)]
+    }
+}
+
+/// Returns the number of plots which can be reached in exactly `steps` steps.
+///
+/// Steps can be undone in pairs, so a plot is reachable if its distance is at most `steps` and
+/// has the same parity.
+pub fn reachable(garden: &Garden, steps: usize, tiled: bool) -> u64 {
+    count_reachable(&distances(garden, steps, tiled), steps)
+}
+
+fn count_reachable(distances: &HashMap<Coord, usize>, steps: usize) -> u64 {
+    distances
+        .values()
+        .filter(|&&dist| dist <= steps && dist % 2 == steps % 2)
+        .count() as u64
+}
+
+/// Returns the distance to every plot within `max_steps` of the start
+fn distances(garden: &Garden, max_steps: usize, tiled: bool) -> HashMap<Coord, usize> {
+    let mut distances = HashMap::from([(garden.start, 0)]);
+    let mut queue = VecDeque::from([garden.start]);
+
+    while let Some(coord) = queue.pop_front() {
+        let dist = distances[&coord];
+
+        if dist == max_steps {
+            continue;
+        }
+
+        for dir in Dir::ALL {
+            let offset = dir.offset();
+            let next = [coord[0] + offset[0], coord[1] + offset[1]];
+
+            if garden.is_plot(next, tiled) && !distances.contains_key(&next) {
+                distances.insert(next, dist + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Returns the number of plots reachable in the infinite garden after `steps` steps.
+///
+/// The start is on an empty row and column, so the reachable area grows as a diamond which
+/// crosses a new ring of tiles every `len` steps. The count is therefore quadratic in the number
+/// of tiles crossed, and is fitted to brute force counts for the first three, then checked
+/// against the fourth.
+///
+/// Returns `None` if the start isn't in the middle of an empty row and column, or the counts
+/// don't fit a quadratic.
+pub fn extrapolate(garden: &Garden, steps: usize) -> Option<u64> {
+    if !start_is_clear(garden) {
+        return None;
+    }
+
+    let remainder = steps % garden.len();
+    let n = i64::try_from(steps / garden.len()).ok()?;
+
+    let distances = distances(garden, remainder + 3 * garden.len(), true);
+
+    let [a, b, c, d] =
+        [0, 1, 2, 3].map(|i| count_reachable(&distances, remainder + i * garden.len()) as i64);
+
+    // Newton forward differences, with second difference `c - 2b + a`
+    let fit = |n: i64| {
+        n.checked_mul(n - 1)?
+            .checked_div(2)?
+            .checked_mul(c + a - 2 * b)?
+            .checked_add(n.checked_mul(b - a)?)?
+            .checked_add(a)
+    };
+
+    if fit(3) != Some(d) {
+        return None;
+    }
+
+    u64::try_from(fit(n)?).ok()
+}
+
+/// Returns true if the map has odd length, with the start in the middle and no rocks in the
+/// start's row or column
+fn start_is_clear(garden: &Garden) -> bool {
+    let len = garden.len() as isize;
+    let mid = len / 2;
+
+    len % 2 == 1
+        && garden.start == [mid, mid]
+        && (0..len).all(|i| garden.is_plot([mid, i], false) && garden.is_plot([i, mid], false))
+}
+
+/// Parses the map of the garden and the starting position
+pub fn parse_input(input: &str) -> Result<Garden, ParseError> {
+    let tiles = Grid::parse(input, "`#`, `.` or `S`", |char| {
+        "#.S".contains(char).then_some(char)
+    })?;
+
+    let len = tiles.width();
+
+    if tiles.height() > len {
+        return Err(ParseError::new(
+            len + 1,
+            1,
+            format_args!("a square map of length {len}"),
+        ));
+    }
+
+    if tiles.height() < len {
+        return Err(unexpected_end(input, format_args!("{len} rows")));
+    }
+
+    let mut starts = tiles.points().filter(|&point| tiles[point] == 'S');
+
+    let start = starts
+        .next()
+        .ok_or_else(|| unexpected_end(input, "a start `S`"))?;
+
+    if let Some(Point { y, x }) = starts.next() {
+        return Err(ParseError::new(y + 1, x + 1, "a single start"));
+    }
+
+    Ok(Garden {
+        rocks: tiles.map(|&tile| tile == '#'),
+        start: [start.y as isize, start.x as isize],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(reachable(&parse_input(EXAMPLE).unwrap(), 6, false), 16);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let garden = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(reachable(&garden, 6, true), 16);
+        assert_eq!(reachable(&garden, 10, true), 50);
+        assert_eq!(reachable(&garden, 50, true), 1594);
+        assert_eq!(reachable(&garden, 100, true), 6536);
+    }
+
+    #[test]
+    fn extrapolate_matches_brute_force() {
+        let garden = parse_input(&generate::<Day21>(0, Some(11))).unwrap();
+
+        for steps in (5..=60).step_by(11) {
+            assert_eq!(
+                extrapolate(&garden, steps),
+                Some(reachable(&garden, steps, true)),
+                "{steps} steps"
+            );
+        }
+
+        for steps in 0..60 {
+            if let Some(count) = extrapolate(&garden, steps) {
+                assert_eq!(count, reachable(&garden, steps, true), "{steps} steps");
+            }
+        }
+    }
+
+    #[test]
+    fn extrapolate_without_clear_start() {
+        // the start's row and column have rocks, so the counts aren't quadratic
+        let garden = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(extrapolate(&garden, 50), None);
+        assert_eq!(extrapolate(&garden, 100), None);
+
+        // the start is walled in, so the count alternates between 0 and 1
+        let garden = parse_input("###\n#S#\n###\n").unwrap();
+
+        assert_eq!(extrapolate(&garden, 7), None);
+        assert_eq!(extrapolate(&garden, 10), None);
+        assert_eq!(part_two(&garden), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("S.S\n...\n...\n").err(),
+            Some(ParseError::new(1, 3, "a single start"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day21>(0, &[EXAMPLE, &generate::<Day21>(0, None)], 1000);
+    }
+}
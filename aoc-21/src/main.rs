@@ -0,0 +1,57 @@
+use std::{env, process::ExitCode};
+
+use aoc_21::{extrapolate, reachable, Day21};
+use aoc_common::{read_input, Solution, DEFAULT_INPUT_PATH};
+
+/// Run as `aoc-21 [PATH] [--brute STEPS]`. With `--brute`, prints the brute force and extrapolated
+/// counts for the infinite garden instead, so the extrapolation can be cross-checked on small step
+/// counts.
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1);
+
+    let mut path = None;
+    let mut brute_steps = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--brute" {
+            brute_steps = args.next().and_then(|steps| steps.parse().ok());
+
+            if brute_steps.is_none() {
+                eprintln!("error: --brute expects a step count");
+                return ExitCode::FAILURE;
+            }
+        } else {
+            path = Some(arg);
+        }
+    }
+
+    let Some(steps) = brute_steps else {
+        return aoc_common::day_main::<Day21>();
+    };
+
+    let path = path.as_deref().unwrap_or(DEFAULT_INPUT_PATH);
+
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let garden = match Day21::parse(&input) {
+        Ok(garden) => garden,
+        Err(err) => {
+            eprintln!("error: failed to parse `{path}`: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{}", reachable(&garden, steps, true));
+    match extrapolate(&garden, steps) {
+        Some(count) => println!("{count}"),
+        None => println!("none"),
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,58 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day22;
+
+/// The side length of the area the bricks fall onto, as in real inputs
+const FOOTPRINT: i64 = 10;
+
+/// `size` is the number of bricks, by default a quarter of a real input as part two settles the
+/// bricks again after removing each one. Each is up to 4 cubes long along one axis, and is dropped in
+/// at a random height, raised until it doesn't overlap any others.
+impl Generator for Day22 {
+    const DEFAULT_SIZE: usize = 300;
+    const MIN_SIZE: usize = 1;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let mut bricks: Vec<[[i64; 3]; 2]> = Vec::new();
+        let max_z = (size as i64).max(1) * 2;
+
+        for _ in 0..size {
+            let axis = rng.index(3);
+            let len = rng.range(0..=3);
+
+            let mut start = [
+                rng.range(0..=FOOTPRINT - 1),
+                rng.range(0..=FOOTPRINT - 1),
+                rng.range(1..=max_z),
+            ];
+
+            // keep the brick within the footprint
+            if axis < 2 {
+                start[axis] = start[axis].min(FOOTPRINT - 1 - len);
+            }
+
+            let mut end = start;
+            end[axis] += len;
+
+            while bricks.iter().any(|other| overlaps(&[start, end], other)) {
+                start[2] += 1;
+                end[2] += 1;
+            }
+
+            bricks.push([start, end]);
+        }
+
+        bricks
+            .iter()
+            .map(|[start, end]| {
+                let [x0, y0, z0] = start;
+                let [x1, y1, z1] = end;
+                format!("{x0},{y0},{z0}~{x1},{y1},{z1}\n")
+            })
+            .collect()
+    }
+}
+
+fn overlaps(a: &[[i64; 3]; 2], b: &[[i64; 3]; 2]) -> bool {
+    (0..3).all(|i| a[0][i] <= b[1][i] && b[0][i] <= a[1][i])
+}
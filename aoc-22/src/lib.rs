@@ -0,0 +1,226 @@
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_common::{lines, Line, MaybeDisplay, ParseError, Solution};
+use rayon::prelude::*;
+
+type Vec3 = nalgebra_glm::TVec3<u16>;
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<Brick>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_two(input)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Brick {
+    pos: Vec3,
+    dim: Vec3,
+}
+
+impl Brick {
+    /// Returns `None` if already on the ground.
+    fn lowered(mut self) -> Option<Self> {
+        if self.pos.z == 1 {
+            return None;
+        }
+
+        self.pos.z -= 1;
+
+        Some(self)
+    }
+
+    fn collides(&self, other: &Self) -> bool {
+        self.pos.x < other.pos.x + other.dim.x
+            && self.pos.x + self.dim.x > other.pos.x
+            && self.pos.y < other.pos.y + other.dim.y
+            && self.pos.y + self.dim.y > other.pos.y
+            && self.pos.z < other.pos.z + other.dim.z
+            && self.pos.z + self.dim.z > other.pos.z
+    }
+}
+
+/// Parses the position and size of each brick
+pub fn parse_input(input: &str) -> Result<Vec<Brick>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (start_str, end_str) = line.split_once(line.text, "~")?;
+
+            let pos = position(line, start_str)?;
+            let end = position(line, end_str)?;
+
+            if pos.z == 0 {
+                return Err(line.error(start_str, "a position above the ground"));
+            }
+
+            if (0..3).any(|i| end[i] < pos[i]) {
+                return Err(line.error(end_str, "an end no less than the start"));
+            }
+
+            Ok(Brick {
+                pos,
+                dim: end - pos + Vec3::new(1, 1, 1),
+            })
+        })
+        .collect()
+}
+
+fn position(line: Line, str: &str) -> Result<Vec3, ParseError> {
+    let mut iter = str.split(',');
+    let mut pos = Vec3::zeros();
+
+    for c in pos.iter_mut() {
+        let token = iter.next().ok_or_else(|| line.error_after(str, "`,`"))?;
+
+        // the far side of a brick is one past its end, which must fit too
+        *c = line
+            .parse(token, "a coordinate")
+            .ok()
+            .filter(|&c| c < u16::MAX)
+            .ok_or_else(|| line.error(token, "a coordinate"))?;
+    }
+
+    if let Some(token) = iter.next() {
+        return Err(line.error(token, "the end of the position"));
+    }
+
+    Ok(pos)
+}
+
+/// Returns the number of bricks which could be disintegrated without any others falling
+pub fn part_one(bricks: &[Brick]) -> u32 {
+    let mut bricks = bricks.to_vec();
+    settle(&mut bricks);
+
+    (0..bricks.len())
+        .into_par_iter()
+        .filter(|removed_index| {
+            // iterate through all other bricks and see if any are able to move
+            for (checked_index, brick) in bricks
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| removed_index != i)
+            {
+                let Some(brick) = brick.lowered() else {
+                    // brick didn't move, check the next one
+                    continue;
+                };
+
+                if bricks
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| removed_index != i && &checked_index != i)
+                    .all(|(_, other)| !brick.collides(other))
+                {
+                    // a brick fell!
+                    return false;
+                }
+            }
+
+            // no bricks moved
+            true
+        })
+        .count() as u32
+}
+
+/// Returns the sum of the number of other bricks which would fall for each brick disintegrated
+pub fn part_two(bricks: &[Brick]) -> u32 {
+    let mut bricks = bricks.to_vec();
+    settle(&mut bricks);
+
+    (0..bricks.len())
+        .into_par_iter()
+        .map(|removed_index| {
+            let mut bricks = bricks.to_owned();
+            bricks.remove(removed_index);
+
+            settle(&mut bricks)
+        })
+        .sum()
+}
+
+/// Returns the number of bricks which moved.
+fn settle(bricks: &mut [Brick]) -> u32 {
+    let mut moved = false;
+    let mut moved_ids = HashSet::new();
+
+    loop {
+        for i in 0..bricks.len() {
+            let Some(brick) = bricks[i].lowered() else {
+                continue;
+            };
+
+            if bricks
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| &i != j)
+                .all(|(_, other)| !brick.collides(other))
+            {
+                moved_ids.insert(i);
+                bricks[i] = brick;
+                moved = true;
+            }
+        }
+
+        if !moved {
+            break;
+        }
+
+        moved = false;
+    }
+
+    moved_ids.len() as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), 5);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), 7);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("1,0,1~1,2,1\n0,0,2~2,0\n").err(),
+            Some(ParseError::new(2, 10, "`,`"))
+        );
+        assert_eq!(
+            parse_input("1,0,0~1,0,1\n").err(),
+            Some(ParseError::new(1, 1, "a position above the ground"))
+        );
+        assert_eq!(
+            parse_input("1,2,1~1,0,1\n").err(),
+            Some(ParseError::new(1, 7, "an end no less than the start"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day22>(0, &[EXAMPLE, &generate::<Day22>(0, None)], 1000);
+    }
+}
@@ -0,0 +1,91 @@
+use aoc_common::{Generator, Rng};
+
+use crate::Day23;
+
+/// The distance between neighbouring junctions, before they are moved by up to `JITTER`
+const SPACING: usize = 22;
+const JITTER: i64 = 2;
+
+/// How close the bend in a corridor can come to the junctions at its ends, which keeps corridors
+/// from touching
+const MARGIN: usize = 6;
+
+/// `size` is the number of rows and columns of junctions, from 2 to 6, as the search for the
+/// longest hike through a grid of 7 takes minutes. Neighbouring junctions are joined by winding
+/// corridors, with slopes leading right and down around each junction.
+impl Generator for Day23 {
+    const DEFAULT_SIZE: usize = 6;
+    const MIN_SIZE: usize = 2;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        let junctions = size.clamp(2, 6);
+        let len = SPACING * (junctions + 1) + 1;
+
+        let positions: Vec<Vec<[usize; 2]>> = (0..junctions)
+            .map(|i| {
+                (0..junctions)
+                    .map(|j| {
+                        [i, j].map(|k| {
+                            (SPACING * (k + 1))
+                                .saturating_add_signed(rng.range(-JITTER..=JITTER) as isize)
+                        })
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut grid = vec![vec![b'#'; len]; len];
+
+        for i in 0..junctions {
+            for j in 0..junctions {
+                let a = positions[i][j];
+
+                if let Some(&b) = positions[i].get(j + 1) {
+                    let turn = a[1] + MARGIN + rng.index(b[1] - a[1] - MARGIN * 2 + 1);
+                    dig(&mut grid, a, [a[0], turn]);
+                    dig(&mut grid, [a[0], turn], [b[0], turn]);
+                    dig(&mut grid, [b[0], turn], b);
+                }
+
+                if let Some(b) = positions.get(i + 1).map(|row| row[j]) {
+                    let turn = a[0] + MARGIN + rng.index(b[0] - a[0] - MARGIN * 2 + 1);
+                    dig(&mut grid, a, [turn, a[1]]);
+                    dig(&mut grid, [turn, a[1]], [turn, b[1]]);
+                    dig(&mut grid, [turn, b[1]], b);
+                }
+            }
+        }
+
+        let first = positions[0][0];
+        let last = positions[junctions - 1][junctions - 1];
+
+        dig(&mut grid, [0, first[1]], first);
+        dig(&mut grid, last, [len - 1, last[1]]);
+
+        // the slopes make every path from the start lead right and down
+        for &[y, x] in positions.iter().flatten() {
+            let arms = [[y - 1, x], [y + 1, x], [y, x - 1], [y, x + 1]];
+
+            if arms.iter().filter(|&&[y, x]| grid[y][x] != b'#').count() > 2 {
+                for (i, [y, x]) in arms.into_iter().enumerate() {
+                    if grid[y][x] != b'#' {
+                        grid[y][x] = if i < 2 { b'v' } else { b'>' };
+                    }
+                }
+            }
+        }
+
+        grid.into_iter()
+            .map(|row| String::from_utf8(row).unwrap() + "\n")
+            .collect()
+    }
+}
+
+/// Clears the straight line of tiles from `a` to `b`
+fn dig(grid: &mut [Vec<u8>], a: [usize; 2], b: [usize; 2]) {
+    let [ys, xs] = [0, 1].map(|i| a[i].min(b[i])..=a[i].max(b[i]));
+
+    for row in &mut grid[ys] {
+        row[xs.clone()].fill(b'.');
+    }
+}
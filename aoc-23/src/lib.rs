@@ -0,0 +1,267 @@
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_common::{Dir, Grid, MaybeAnswer, MaybeDisplay, ParseError, Point, Solution};
+
+/// The most junctions the search can track, one per bit of a `u64`
+const MAX_JUNCTIONS: usize = u64::BITS as usize;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        hike(input, true)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        hike(input, false)
+    }
+}
+
+/// Returns the length of the longest hike, where slopes can only be walked down, or `None` if the
+/// end can't be reached or there are too many junctions to search
+pub fn part_one(map: &Map) -> Option<u32> {
+    hike(map, true).answer
+}
+
+/// As [`part_one`], but slopes can be walked in any direction
+pub fn part_two(map: &Map) -> Option<u32> {
+    hike(map, false).answer
+}
+
+fn hike(map: &Map, slippery: bool) -> MaybeAnswer<u32> {
+    match Graph::new(map, slippery) {
+        Some(graph) => MaybeAnswer::new(longest_hike(&graph), "no hike reaches the end"),
+        None => MaybeAnswer::new(None, "more than 64 junctions"),
+    }
+}
+
+/// Directions and their corresponding slope tiles
+const DIRS: [(Dir, u8); 4] = [
+    (Dir::Up, b'^'),
+    (Dir::Right, b'>'),
+    (Dir::Down, b'v'),
+    (Dir::Left, b'<'),
+];
+
+pub struct Map {
+    tiles: Grid<u8>,
+}
+
+impl Map {
+    fn tile(&self, coord: Point) -> u8 {
+        self.tiles[coord]
+    }
+
+    /// Returns the adjacent point in direction `dir`, if it isn't a wall
+    fn step(&self, point: Point, dir: Dir) -> Option<Point> {
+        self.tiles
+            .step(point, dir)
+            .filter(|&point| self.tile(point) != b'#')
+    }
+
+    /// Returns all points which can be moved to from `coord`. When `slippery`, a slope can only be
+    /// left downhill.
+    fn moves(&self, coord: Point, slippery: bool) -> impl Iterator<Item = Point> + '_ {
+        let tile = self.tile(coord);
+
+        DIRS.iter()
+            .filter(move |&&(_, slope)| !slippery || tile == b'.' || tile == slope)
+            .filter_map(move |&(dir, _)| self.step(coord, dir))
+    }
+
+    fn is_junction(&self, coord: Point) -> bool {
+        DIRS.iter()
+            .filter_map(|&(dir, _)| self.step(coord, dir))
+            .count()
+            > 2
+    }
+}
+
+/// The map compressed into the junctions between corridors, where the trail can branch
+struct Graph {
+    /// `(junction index, distance)` pairs reachable from each junction
+    edges: Vec<Vec<(usize, u32)>>,
+    start: usize,
+    end: usize,
+}
+
+impl Graph {
+    /// Returns `None` if there are more than `MAX_JUNCTIONS` junctions, including the start and
+    /// end.
+    fn new(map: &Map, slippery: bool) -> Option<Self> {
+        let bottom = map.tiles.height() - 1;
+        let start = Point::new(0, path_in_row(&map.tiles, 0).unwrap());
+        let end = Point::new(bottom, path_in_row(&map.tiles, bottom).unwrap());
+
+        let junctions: HashMap<Point, usize> = [start, end]
+            .into_iter()
+            .chain(
+                map.tiles
+                    .points()
+                    .filter(|&coord| map.tile(coord) != b'#' && map.is_junction(coord)),
+            )
+            .enumerate()
+            .map(|(i, coord)| (coord, i))
+            .collect();
+
+        // visited junctions are tracked in a u64 bitmask
+        if junctions.len() > MAX_JUNCTIONS {
+            return None;
+        }
+
+        let mut edges = vec![Vec::new(); junctions.len()];
+
+        for (&coord, &i) in &junctions {
+            edges[i] = map
+                .moves(coord, slippery)
+                .filter_map(|next| follow_corridor(map, &junctions, coord, next, slippery))
+                .collect();
+        }
+
+        // The end is a dead end, so its only neighbouring junction must head straight for it;
+        // leaving in any other direction would cut the end off.
+        for edges in &mut edges {
+            if let Some(&edge) = edges.iter().find(|(j, _)| *j == 1) {
+                *edges = vec![edge];
+            }
+        }
+
+        Some(Self {
+            edges,
+            start: 0,
+            end: 1,
+        })
+    }
+}
+
+/// Walks the corridor leaving junction `prev` through `curr`, and returns the junction at its
+/// other end along with the distance to it. Returns `None` if the corridor is a dead end.
+fn follow_corridor(
+    map: &Map,
+    junctions: &HashMap<Point, usize>,
+    mut prev: Point,
+    mut curr: Point,
+    slippery: bool,
+) -> Option<(usize, u32)> {
+    let mut dist = 1;
+
+    loop {
+        if let Some(&junction) = junctions.get(&curr) {
+            return Some((junction, dist));
+        }
+
+        let next = map.moves(curr, slippery).find(|&next| next != prev)?;
+
+        prev = curr;
+        curr = next;
+        dist += 1;
+    }
+}
+
+/// Returns the length of the longest hike from the start to the end, or `None` if there's no way
+/// to reach it
+fn longest_hike(graph: &Graph) -> Option<u32> {
+    longest_hike_recursive(graph, graph.start, 1 << graph.start)
+}
+
+/// Returns the longest path from `junction` to the end which avoids the `visited` junctions
+fn longest_hike_recursive(graph: &Graph, junction: usize, visited: u64) -> Option<u32> {
+    if junction == graph.end {
+        return Some(0);
+    }
+
+    graph.edges[junction]
+        .iter()
+        .filter(|(next, _)| visited & 1 << next == 0)
+        .filter_map(|&(next, dist)| {
+            longest_hike_recursive(graph, next, visited | 1 << next).map(|rest| rest + dist)
+        })
+        .max()
+}
+
+/// Parses the map of the hiking trails
+pub fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let tiles = Grid::parse(input, "a path, forest or slope", |char| {
+        "#.^>v<".contains(char).then_some(char as u8)
+    })?;
+
+    // the start is the path in the top row, and the end is the path in the bottom row
+    if path_in_row(&tiles, 0).is_none() {
+        return Err(ParseError::new(1, 1, "a start path"));
+    }
+
+    if tiles.height() == 1 || path_in_row(&tiles, tiles.height() - 1).is_none() {
+        return Err(ParseError::new(tiles.height(), 1, "an end path"));
+    }
+
+    Ok(Map { tiles })
+}
+
+/// Returns the column of the path in row `y`
+fn path_in_row(tiles: &Grid<u8>, y: usize) -> Option<usize> {
+    tiles.row(y).iter().position(|&tile| tile == b'.')
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), Some(94));
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Some(154));
+    }
+
+    #[test]
+    fn unreachable_end() {
+        let map = parse_input("#.#\n###\n#.#\n").unwrap();
+
+        assert_eq!(part_one(&map), None);
+        assert_eq!(part_two(&map), None);
+    }
+
+    #[test]
+    fn too_many_junctions() {
+        // every tile off the edges of an open field is a junction
+        let field = format!("{}\n", ".".repeat(41)).repeat(41);
+        let map = parse_input(&field).unwrap();
+
+        assert_eq!(
+            Day23::part_two(&map).display(),
+            Err("more than 64 junctions")
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("###\n#.#\n#.#\n").err(),
+            Some(ParseError::new(1, 1, "a start path"))
+        );
+        assert_eq!(
+            parse_input("#.#\n###\n").err(),
+            Some(ParseError::new(2, 1, "an end path"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day23>(0, &[EXAMPLE, &generate::<Day23>(0, None)], 1000);
+    }
+}
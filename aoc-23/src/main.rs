@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_23::Day23;
+
+fn main() -> ExitCode {
+    aoc_common::day_main::<Day23>()
+}
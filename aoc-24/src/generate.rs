@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use aoc_common::{Generator, Rng};
+
+use crate::Day24;
+
+/// `size` is the number of hailstones. A rock is thrown first, and each hailstone is placed so
+/// that the rock hits it at a different time.
+impl Generator for Day24 {
+    const DEFAULT_SIZE: usize = 300;
+    const MIN_SIZE: usize = 3;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_with_rock(rng, size).0
+    }
+}
+
+/// Returns the input along with the rock's starting position
+fn generate_with_rock(rng: &mut Rng, size: usize) -> (String, [i64; 3]) {
+    let rock_pos = [(); 3].map(|()| rng.range(250_000_000_000_000..=350_000_000_000_000));
+    let rock_vel = [(); 3].map(|()| rng.range(-200..=200));
+
+    let mut times = HashSet::new();
+    let mut input = String::new();
+
+    while times.len() < size.max(3) {
+        let time = rng.range(10_000_000_000..=500_000_000_000);
+        let vel = [(); 3].map(|()| rng.range(-300..=300));
+
+        if vel == rock_vel || !times.insert(time) {
+            continue;
+        }
+
+        // where the hailstone must start to be where the rock is at `time`
+        let pos: [i64; 3] = std::array::from_fn(|i| rock_pos[i] + (rock_vel[i] - vel[i]) * time);
+
+        input.push_str(&format!(
+            "{}, {}, {} @ {}, {}, {}\n",
+            pos[0], pos[1], pos[2], vel[0], vel[1], vel[2]
+        ));
+    }
+
+    (input, rock_pos)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Part};
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..10 {
+            let (input, rock) = generate_with_rock(&mut Rng::new(seed), 20);
+            let run = solve::<Day24>(&input, &Part::ALL).unwrap();
+
+            assert_eq!(
+                run.answers[1].value,
+                Some(rock.iter().sum::<i64>().to_string())
+            );
+        }
+    }
+}
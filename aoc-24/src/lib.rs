@@ -0,0 +1,284 @@
+mod generate;
+
+use std::{array::from_fn, ops::RangeInclusive, str::FromStr};
+
+use aoc_common::{lines, Line, MaybeAnswer, MaybeDisplay, ParseError, Solution};
+use num::{BigInt, BigRational, ToPrimitive, Zero};
+
+type Vec3 = [i128; 3];
+
+const TEST_AREA: RangeInclusive<i128> = 200000000000000..=400000000000000;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = Vec<Hailstone>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_two(input), "no three hailstones determine the rock")
+    }
+}
+
+/// Returns the number of pairs of hailstones whose paths cross within the test area, ignoring
+/// the z axis
+pub fn part_one(hailstones: &[Hailstone]) -> usize {
+    intersections(hailstones, TEST_AREA)
+}
+
+/// Returns the sum of the coordinates of the position to throw a rock from so that it hits every
+/// hailstone, or `None` if there aren't three hailstones which determine it
+pub fn part_two(hailstones: &[Hailstone]) -> Option<i128> {
+    // any three hailstones determine the rock, unless some of their velocities are parallel
+    let rock = hailstones
+        .windows(3)
+        .find_map(|window| rock_position(&window[0], &window[1], &window[2]))?;
+
+    Some(rock.iter().sum())
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Hailstone {
+    pos: Vec3,
+    vel: Vec3,
+}
+
+/// Returns the number of pairs of hailstones whose future xy paths cross inside `area`
+fn intersections(hailstones: &[Hailstone], area: RangeInclusive<i128>) -> usize {
+    hailstones
+        .iter()
+        .enumerate()
+        .flat_map(|(i, a)| hailstones[i + 1..].iter().map(move |b| (a, b)))
+        .filter(|(a, b)| {
+            let Some((coords, denom)) = path_intersection(a, b) else {
+                return false;
+            };
+
+            let area = area.start() * denom..=area.end() * denom;
+            coords.iter().all(|coord| area.contains(coord))
+        })
+        .count()
+}
+
+/// Returns the xy coords where the future paths of `a` and `b` cross, ignoring the z axis. The
+/// coords are returned as numerators over a shared positive denominator, `([x, y], denom)`.
+fn path_intersection(a: &Hailstone, b: &Hailstone) -> Option<([i128; 2], i128)> {
+    let mut det = a.vel[0] * b.vel[1] - a.vel[1] * b.vel[0];
+
+    if det == 0 {
+        // parallel paths
+        return None;
+    }
+
+    let dx = b.pos[0] - a.pos[0];
+    let dy = b.pos[1] - a.pos[1];
+
+    // times at which each hailstone reaches the crossing, scaled by `det`
+    let mut t_a = dx * b.vel[1] - dy * b.vel[0];
+    let mut t_b = dx * a.vel[1] - dy * a.vel[0];
+
+    if det < 0 {
+        det = -det;
+        t_a = -t_a;
+        t_b = -t_b;
+    }
+
+    if t_a < 0 || t_b < 0 {
+        // the paths crossed in the past
+        return None;
+    }
+
+    Some((from_fn(|i| a.pos[i] * det + t_a * a.vel[i]), det))
+}
+
+/// Returns the position the rock must be thrown from to hit `a`, `b` and `c`.
+///
+/// For each hailstone `i`, the rock must satisfy `(p - p_i) × (v - v_i) = 0`. Subtracting that
+/// equation for two hailstones cancels the nonlinear `p × v` term, leaving three linear equations
+/// in `p` and `v`. Two such pairs give a system of six, which is solved exactly.
+fn rock_position(a: &Hailstone, b: &Hailstone, c: &Hailstone) -> Option<Vec3> {
+    let mut system = linear_equations(a, b).to_vec();
+    system.extend(linear_equations(a, c));
+
+    let solution = solve(system)?;
+
+    let mut position = [0; 3];
+
+    for (coord, val) in position.iter_mut().zip(&solution) {
+        if !val.is_integer() {
+            return None;
+        }
+
+        *coord = val.to_integer().to_i128()?;
+    }
+
+    Some(position)
+}
+
+/// Returns the rows `[px, py, pz, vx, vy, vz, rhs]` of
+/// `p × (v_b - v_a) + (p_b - p_a) × v = p_b × v_b - p_a × v_a`
+fn linear_equations(a: &Hailstone, b: &Hailstone) -> [Vec<BigRational>; 3] {
+    let w: Vec3 = from_fn(|i| b.vel[i] - a.vel[i]);
+    let d: Vec3 = from_fn(|i| b.pos[i] - a.pos[i]);
+
+    let rhs = cross(b.pos, b.vel);
+    let rhs: Vec3 = from_fn(|i| rhs[i] - cross(a.pos, a.vel)[i]);
+
+    [
+        [0, w[2], -w[1], 0, -d[2], d[1], rhs[0]],
+        [-w[2], 0, w[0], d[2], 0, -d[0], rhs[1]],
+        [w[1], -w[0], 0, -d[1], d[0], 0, rhs[2]],
+    ]
+    .map(|row| row.into_iter().map(rational).collect())
+}
+
+/// Solves an augmented square system with Gauss-Jordan elimination. Returns `None` if it is
+/// singular.
+fn solve(mut system: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let len = system.len();
+
+    for col in 0..len {
+        let pivot = (col..len).find(|&row| !system[row][col].is_zero())?;
+        system.swap(col, pivot);
+
+        let pivot_row = system[col].clone();
+
+        for (i, row) in system.iter_mut().enumerate() {
+            if i == col || row[col].is_zero() {
+                continue;
+            }
+
+            let factor = &row[col] / &pivot_row[col];
+
+            for (val, pivot_val) in row.iter_mut().zip(&pivot_row) {
+                *val -= &factor * pivot_val;
+            }
+        }
+    }
+
+    Some(
+        system
+            .into_iter()
+            .enumerate()
+            .map(|(i, row)| &row[len] / &row[i])
+            .collect(),
+    )
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn rational(val: i128) -> BigRational {
+    BigRational::from_integer(BigInt::from(val))
+}
+
+/// Parses the position and velocity of each hailstone.
+///
+/// Positions must fit in an `i64` and velocities in an `i16`, far beyond real inputs, so that the
+/// products in [`path_intersection`] and [`cross`] fit in an `i128`.
+pub fn parse_input(input: &str) -> Result<Vec<Hailstone>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (pos, vel) = line.split_once(line.text, "@")?;
+
+            Ok(Hailstone {
+                pos: vector::<i64>(line, pos, "a position")?,
+                vel: vector::<i16>(line, vel, "a velocity")?,
+            })
+        })
+        .collect()
+}
+
+fn vector<T>(line: Line, str: &str, expected: &str) -> Result<Vec3, ParseError>
+where
+    T: FromStr + Into<i128>,
+{
+    let mut iter = str.split(',');
+    let mut vector = [0; 3];
+
+    for c in &mut vector {
+        let token = iter
+            .next()
+            .ok_or_else(|| line.error_after(str, "`,`"))?
+            .trim();
+
+        *c = line.parse::<T>(token, expected)?.into();
+    }
+
+    if let Some(token) = iter.next() {
+        return Err(line.error(token, "`@` or the end of the line"));
+    }
+
+    Ok(vector)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(intersections(&parse_input(EXAMPLE).unwrap(), 7..=27), 2);
+    }
+
+    #[test]
+    fn part_two_example() {
+        assert_eq!(part_two(&parse_input(EXAMPLE).unwrap()), Some(47));
+    }
+
+    #[test]
+    fn part_two_too_few_hailstones() {
+        let hailstones = parse_input(EXAMPLE).unwrap();
+
+        assert_eq!(part_two(&[]), None);
+        assert_eq!(part_two(&hailstones[..2]), None);
+    }
+
+    #[test]
+    fn coords_in_range() {
+        let hailstone = |pos: &str, vel: &str| parse_input(&format!("{pos}, 0, 0 @ {vel}, 0, 0\n"));
+
+        assert!(hailstone("-9223372036854775808", "-32768").is_ok());
+        assert_eq!(
+            hailstone("9223372036854775808", "1").err(),
+            Some(ParseError::new(1, 1, "a position"))
+        );
+        assert_eq!(
+            hailstone("1", "32768").err(),
+            Some(ParseError::new(1, 11, "a velocity"))
+        );
+
+        // the most extreme hailstones don't overflow
+        let extremes = [i64::MIN, i64::MAX]
+            .into_iter()
+            .flat_map(|pos| {
+                [i16::MIN, i16::MAX].map(|vel| format!("{pos}, {pos}, {pos} @ {vel}, -1, {vel}\n"))
+            })
+            .collect::<String>();
+        let hailstones = parse_input(&extremes).unwrap();
+
+        intersections(&hailstones, TEST_AREA);
+        part_two(&hailstones);
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day24>(0, &[EXAMPLE, &generate::<Day24>(0, None)], 1000);
+    }
+}
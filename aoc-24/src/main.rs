@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_24::Day24;
+
+fn main() -> ExitCode {
+    aoc_common::day_main::<Day24>()
+}
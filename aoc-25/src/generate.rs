@@ -0,0 +1,101 @@
+use std::collections::{BTreeMap, HashSet};
+
+use aoc_common::{Generator, Rng};
+
+use crate::{Day25, CUT_SIZE};
+
+/// Every component in a cluster is wired to this many others before it, so a cluster can't be
+/// split by cutting fewer wires than the clusters are joined by
+const LINKS: usize = CUT_SIZE + 1;
+
+/// `size` is the number of components, at least 10, which are split into two clusters joined by
+/// three wires.
+impl Generator for Day25 {
+    const DEFAULT_SIZE: usize = 1500;
+    const MIN_SIZE: usize = (LINKS + 1) * 2;
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_with_sides(rng, size).0
+    }
+}
+
+/// Returns the input along with the number of components in each cluster
+fn generate_with_sides(rng: &mut Rng, size: usize) -> (String, [usize; 2]) {
+    let size = size.max((LINKS + 1) * 2);
+    let first_len = rng.index(size / 2 - LINKS) + LINKS + 1;
+
+    let mut labels = HashSet::new();
+
+    let labels: Vec<String> = (0..size)
+        .map(|_| loop {
+            let label: String = (0..3)
+                .map(|_| char::from(b'a' + rng.index(26) as u8))
+                .collect();
+
+            if labels.insert(label.clone()) {
+                break label;
+            }
+        })
+        .collect();
+
+    let mut wires = Vec::new();
+
+    for cluster in [0..first_len, first_len..size] {
+        let start = cluster.start;
+
+        for i in cluster.clone() {
+            let mut earlier: Vec<_> = (start..i).collect();
+            rng.shuffle(&mut earlier);
+
+            // the first few components are all wired to each other
+            wires.extend(earlier.into_iter().take(LINKS).map(|j| [i, j]));
+        }
+    }
+
+    for _ in 0..CUT_SIZE {
+        loop {
+            let wire = [
+                rng.index(first_len),
+                first_len + rng.index(size - first_len),
+            ];
+
+            if !wires.contains(&wire) {
+                wires.push(wire);
+                break;
+            }
+        }
+    }
+
+    // each wire is listed once, on the line of either of its components
+    let mut lines: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+
+    for mut wire in wires {
+        rng.shuffle(&mut wire);
+        lines.entry(wire[0]).or_default().push(&labels[wire[1]]);
+    }
+
+    let mut lines: Vec<_> = lines
+        .into_iter()
+        .map(|(component, others)| format!("{}: {}\n", labels[component], others.join(" ")))
+        .collect();
+    rng.shuffle(&mut lines);
+
+    (lines.concat(), [first_len, size - first_len])
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{solve, Part};
+
+    use super::*;
+
+    #[test]
+    fn generated_inputs_solve() {
+        for seed in 0..10 {
+            let (input, [a, b]) = generate_with_sides(&mut Rng::new(seed), 100);
+            let run = solve::<Day25>(&input, &[Part::One]).unwrap();
+
+            assert_eq!(run.answers[0].value, Some((a * b).to_string()));
+        }
+    }
+}
@@ -0,0 +1,240 @@
+mod generate;
+
+use std::collections::{HashMap, VecDeque};
+
+use aoc_common::{lines, Line, MaybeAnswer, MaybeDisplay, ParseError, Solution};
+
+/// The number of wires which must be cut
+const CUT_SIZE: usize = 3;
+
+/// Why there's no answer for a graph which can't be split by cutting `CUT_SIZE` wires
+const NO_CUT: &str = "no three wires split the components in two";
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Graph<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_one(input), NO_CUT)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+        MaybeAnswer::new(part_two(input), NO_CUT)
+    }
+}
+
+/// Returns the product of the sizes of the two groups left after cutting three wires, or `None`
+/// if no three wires split the components
+pub fn part_one(graph: &Graph) -> Option<usize> {
+    let cut = min_cut(graph)?;
+    Some(cut.side_len * (graph.labels.len() - cut.side_len))
+}
+
+/// There is no second puzzle on the last day, so this lists the cut wires as `a/b` pairs instead
+pub fn part_two(graph: &Graph) -> Option<String> {
+    let wires = min_cut(graph)?
+        .edges
+        .iter()
+        .map(|[a, b]| format!("{}/{}", graph.labels[*a], graph.labels[*b]))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    Some(wires)
+}
+
+pub struct Graph<'a> {
+    labels: Vec<&'a str>,
+    adjacent: Vec<Vec<usize>>,
+}
+
+struct Cut {
+    edges: Vec<[usize; 2]>,
+    /// The number of components on one side of the cut
+    side_len: usize,
+}
+
+/// Flow along each directed edge, where `flow[[a, b]] == -flow[[b, a]]`
+type Flow = HashMap<[usize; 2], i32>;
+
+/// Finds the cut by computing the max flow from the first component to every other component.
+/// Each wire has a capacity of one, so the flow is `CUT_SIZE` exactly when the two components are
+/// on opposite sides of the cut. Returns `None` if there is no such cut.
+fn min_cut(graph: &Graph) -> Option<Cut> {
+    (1..graph.labels.len()).find_map(|sink| cut_between(graph, 0, sink))
+}
+
+/// Runs Edmonds-Karp from `source` to `sink`, and returns the cut between them if the max flow is
+/// `CUT_SIZE`.
+fn cut_between(graph: &Graph, source: usize, sink: usize) -> Option<Cut> {
+    let mut flow = Flow::new();
+
+    for _ in 0..CUT_SIZE {
+        let came_from = residual_bfs(graph, &flow, source);
+        augment(&mut flow, &came_from, sink)?;
+    }
+
+    let came_from = residual_bfs(graph, &flow, source);
+
+    if came_from[sink].is_some() {
+        // the flow can be increased further
+        return None;
+    }
+
+    // the components still reachable through the residual graph form one side of the cut
+    let edges = graph
+        .adjacent
+        .iter()
+        .enumerate()
+        .filter(|(a, _)| came_from[*a].is_some())
+        .flat_map(|(a, adjacent)| adjacent.iter().map(move |&b| [a, b]))
+        .filter(|[_, b]| came_from[*b].is_none())
+        .collect();
+
+    Some(Cut {
+        edges,
+        side_len: came_from.iter().flatten().count(),
+    })
+}
+
+/// Returns the previous component along a shortest path through the residual graph, for each
+/// component reachable from `source`
+fn residual_bfs(graph: &Graph, flow: &Flow, source: usize) -> Vec<Option<usize>> {
+    let mut came_from = vec![None; graph.labels.len()];
+    came_from[source] = Some(source);
+
+    let mut queue = VecDeque::from([source]);
+
+    while let Some(a) = queue.pop_front() {
+        for &b in &graph.adjacent[a] {
+            let residual = 1 - flow.get(&[a, b]).unwrap_or(&0);
+
+            if residual > 0 && came_from[b].is_none() {
+                came_from[b] = Some(a);
+                queue.push_back(b);
+            }
+        }
+    }
+
+    came_from
+}
+
+/// Pushes one unit of flow along the path to `sink`. Returns `None` if `sink` is unreachable.
+fn augment(flow: &mut Flow, came_from: &[Option<usize>], sink: usize) -> Option<()> {
+    let mut b = sink;
+
+    while let Some(a) = came_from[b].filter(|&a| a != b) {
+        *flow.entry([a, b]).or_default() += 1;
+        *flow.entry([b, a]).or_default() -= 1;
+        b = a;
+    }
+
+    came_from[sink].map(|_| ())
+}
+
+/// Parses the wiring diagram into a graph of components
+pub fn parse_input(input: &str) -> Result<Graph<'_>, ParseError> {
+    let mut labels = Vec::new();
+    let mut indices = HashMap::new();
+    let mut adjacent = Vec::new();
+
+    let mut index = |label| {
+        *indices.entry(label).or_insert_with(|| {
+            labels.push(label);
+            adjacent.push(Vec::new());
+            labels.len() - 1
+        })
+    };
+
+    let mut edges: Vec<[usize; 2]> = Vec::new();
+
+    for line in lines(input) {
+        let (a, others) = line.split_once(line.text, ": ")?;
+        let a = component(line, a)?;
+
+        let mut others = others.split_whitespace().peekable();
+
+        if others.peek().is_none() {
+            return Err(line.error_after(line.text, "a connected component"));
+        }
+
+        for b in others {
+            edges.push([a, component(line, b)?].map(&mut index));
+        }
+    }
+
+    for [a, b] in edges {
+        adjacent[a].push(b);
+        adjacent[b].push(a);
+    }
+
+    Ok(Graph { labels, adjacent })
+}
+
+fn component<'a>(line: Line<'a>, label: &'a str) -> Result<&'a str, ParseError> {
+    if !label.is_empty() && label.bytes().all(|b| b.is_ascii_alphanumeric()) {
+        Ok(label)
+    } else {
+        Err(line.error(label, "a component label"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::{fuzz_parse, generate};
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example");
+
+    #[test]
+    fn part_one_example() {
+        assert_eq!(part_one(&parse_input(EXAMPLE).unwrap()), Some(54));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let mut wires: Vec<_> = part_two(&parse_input(EXAMPLE).unwrap())
+            .unwrap()
+            .split(' ')
+            .map(|wire| {
+                let mut labels: Vec<_> = wire.split('/').collect();
+                labels.sort_unstable();
+                labels.join("/")
+            })
+            .collect();
+        wires.sort_unstable();
+
+        assert_eq!(wires, ["bvb/cmg", "hfx/pzl", "jqt/nvd"]);
+    }
+
+    #[test]
+    fn no_cut() {
+        let triangle = parse_input("a: b c\nb: c\n").unwrap();
+
+        assert_eq!(part_one(&triangle), None);
+        assert_eq!(part_two(&triangle), None);
+        assert_eq!(part_one(&parse_input("").unwrap()), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            parse_input("jqt: rhn xhk\nrhn:\n").err(),
+            Some(ParseError::new(2, 5, "`: `"))
+        );
+        assert_eq!(
+            parse_input("jqt: rh-n\n").err(),
+            Some(ParseError::new(1, 6, "a component label"))
+        );
+    }
+
+    #[test]
+    fn parse_never_panics() {
+        fuzz_parse::<Day25>(0, &[EXAMPLE, &generate::<Day25>(0, None)], 1000);
+    }
+}
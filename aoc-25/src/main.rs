@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use aoc_25::Day25;
+
+fn main() -> ExitCode {
+    aoc_common::day_main::<Day25>()
+}
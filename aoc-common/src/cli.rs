@@ -0,0 +1,37 @@
+use std::{env, process::ExitCode};
+
+use crate::{read_input, solve, Part, Solution};
+
+/// The input path used when none is given on the command line
+pub const DEFAULT_INPUT_PATH: &str = "input";
+
+/// Entry point for a single day's binary, run as `aoc-NN [PATH]`.
+///
+/// Reads the input from `PATH`, from stdin if it is `-`, or from `input` if it is omitted, and
+/// prints the answer to each part on its own line.
+pub fn day_main<S: Solution>() -> ExitCode {
+    let path = env::args().nth(1);
+    let path = path.as_deref().unwrap_or(DEFAULT_INPUT_PATH);
+
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let run = match solve::<S>(&input, &Part::ALL) {
+        Ok(run) => run,
+        Err(err) => {
+            eprintln!("error: failed to parse `{path}`: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    for answer in run.answers {
+        println!("{answer}");
+    }
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,78 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// The states reached by repeatedly stepping from an initial state, which eventually repeat.
+///
+/// The states before `start` are only reached once, then the following `len` states repeat
+/// forever.
+#[derive(Clone, Debug)]
+pub struct Cycle<S> {
+    /// The index of the first state in the cycle, often called mu
+    pub start: usize,
+    /// The number of states in the cycle, often called lambda
+    pub len: usize,
+    /// Every state up to the end of the first pass of the cycle
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// Returns the state after `n` steps from the initial state.
+    pub fn nth(&self, n: usize) -> &S {
+        if n < self.states.len() {
+            &self.states[n]
+        } else {
+            &self.states[self.start + (n - self.start) % self.len]
+        }
+    }
+}
+
+/// Steps from `initial` until a state repeats, remembering every state along the way.
+///
+/// Loops forever if `step` never repeats a state.
+pub fn find_cycle<S, F>(initial: S, mut step: F) -> Cycle<S>
+where
+    S: Clone + Eq + Hash,
+    F: FnMut(&S) -> S,
+{
+    let mut indices = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+
+    loop {
+        let next = step(states.last().unwrap());
+
+        if let Some(&start) = indices.get(&next) {
+            return Cycle {
+                start,
+                len: states.len() - start,
+                states,
+            };
+        }
+
+        indices.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles() {
+        // 2, 4, 8, 16, 12, 4, ...
+        let cycle = find_cycle(2, |n| n * 2 % 20);
+
+        assert_eq!((cycle.start, cycle.len), (1, 4));
+        assert_eq!(cycle.nth(0), &2);
+        assert_eq!(cycle.nth(4), &12);
+        assert_eq!(cycle.nth(5), &4);
+        assert_eq!(cycle.nth(1000000003), &16);
+
+        let cycle = find_cycle(0, |n| (n + 1) % 3);
+        assert_eq!((cycle.start, cycle.len), (0, 3));
+        assert_eq!(cycle.nth(7), &1);
+
+        let cycle = find_cycle('a', |&c| c);
+        assert_eq!((cycle.start, cycle.len), (0, 1));
+        assert_eq!(cycle.nth(usize::MAX), &'a');
+    }
+}
@@ -0,0 +1,106 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Rng, Solution};
+
+/// Characters which are likely to trip up a parser, as they are separators in some puzzle or
+/// otherwise unusual
+const INTERESTING: &[&str] = &[
+    "\n",
+    "\n\n",
+    " ",
+    "  ",
+    ",",
+    ":",
+    ";",
+    "=",
+    "-",
+    "~",
+    "@",
+    "(",
+    ")",
+    "{",
+    "}",
+    "|",
+    "#",
+    ".",
+    "0",
+    "1",
+    "9",
+    "-1",
+    "4294967296",
+    "99999999999999999999999",
+    "é",
+    "\u{feff}",
+    "\t",
+    "\r\n",
+];
+
+/// Calls `S::parse` on `runs` inputs made by mutating `samples`, along with some random bytes,
+/// and panics with the input if it panics rather than returning an error.
+///
+/// This is a plain stand-in for a coverage guided fuzzer, cheap enough to run as a test.
+pub fn fuzz_parse<S: Solution>(seed: u64, samples: &[&str], runs: usize) {
+    let mut rng = Rng::new(seed);
+
+    for run in 0..runs {
+        let input = if samples.is_empty() || run % 10 == 0 {
+            random_text(&mut rng)
+        } else {
+            let sample = *rng.choose(samples);
+            mutate(&mut rng, sample)
+        };
+
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| {
+            let _ = S::parse(&input);
+        }));
+
+        if parsed.is_err() {
+            panic!("parsing panicked on {input:?}");
+        }
+    }
+}
+
+/// Returns up to 64 random bytes, with any invalid UTF-8 replaced
+fn random_text(rng: &mut Rng) -> String {
+    let bytes: Vec<_> = (0..rng.index(65)).map(|_| rng.below(256) as u8).collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Applies a few random edits to `sample`
+fn mutate(rng: &mut Rng, sample: &str) -> String {
+    let mut chars: Vec<char> = sample.chars().collect();
+
+    for _ in 0..rng.index(4) + 1 {
+        let at = rng.index(chars.len() + 1);
+        let len = rng.index(8).min(chars.len() - at);
+
+        match rng.index(6) {
+            // delete a few characters
+            0 => {
+                chars.drain(at..at + len);
+            }
+            // replace them with something unusual
+            1 => {
+                chars.splice(at..at + len, rng.choose(INTERESTING).chars());
+            }
+            // insert something unusual
+            2 => {
+                chars.splice(at..at, rng.choose(INTERESTING).chars());
+            }
+            // repeat a few characters
+            3 => {
+                let copy: Vec<_> = chars[at..at + len].to_vec();
+                chars.splice(at..at, copy);
+            }
+            // cut the input short
+            4 => chars.truncate(at),
+            // replace a character with a random one
+            _ if at < chars.len() => {
+                chars[at] = char::from(rng.below(128) as u8);
+            }
+            _ => {}
+        }
+    }
+
+    chars.into_iter().collect()
+}
@@ -0,0 +1,270 @@
+use std::ops::RangeInclusive;
+
+/// Generates random puzzle inputs, so solutions can be tested without sharing real inputs and at
+/// sizes beyond them.
+pub trait Generator {
+    /// The size used when none is given, roughly that of a real input unless the solution would
+    /// take too long on one
+    const DEFAULT_SIZE: usize;
+
+    /// The smallest size with an answer to both parts. Smaller sizes are raised to it.
+    const MIN_SIZE: usize;
+
+    /// Returns a valid input of the given size, which has an answer to both parts that the
+    /// solution finds within seconds at the default size. What the size measures depends on the
+    /// day, e.g. the number of lines, or the side length of a grid.
+    fn generate(rng: &mut Rng, size: usize) -> String;
+}
+
+/// Generates an input for `G` from `seed`, at its default size unless `size` is given.
+pub fn generate<G: Generator>(seed: u64, size: Option<usize>) -> String {
+    let size = size.unwrap_or(G::DEFAULT_SIZE).max(G::MIN_SIZE);
+    G::generate(&mut Rng::new(seed), size)
+}
+
+/// A small, seedable random number generator (SplitMix64), so a seed always generates the same
+/// input.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `0..n`, which must not be empty.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+
+        // reject the values which would make the lowest remainders more likely
+        let zone = u64::MAX - u64::MAX % n;
+
+        loop {
+            let x = self.next_u64();
+
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    /// Returns a number in `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().abs_diff(*range.start()) + 1;
+        range.start().wrapping_add_unsigned(self.below(span))
+    }
+
+    /// Returns an index into a slice of length `len`
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    /// Returns true with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64) < p * (1_u64 << 53) as f64
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// Returns a random simple loop on the grid of points `[y, x]` with `y <= height` and
+/// `x <= width`. Consecutive points are one step apart, as are the last and first.
+///
+/// The loop is the boundary of a random region of the `height` by `width` cells between the
+/// points. The region is grown a cell at a time, and only by cells which keep it free of holes
+/// and of cells touching only at a corner, so its boundary never meets itself. Cells which would
+/// complete a 2 by 2 block of the region are only added with probability `fill`, so a low `fill`
+/// makes a narrow, winding region.
+pub fn random_loop(rng: &mut Rng, height: usize, width: usize, fill: f64) -> Vec<[usize; 2]> {
+    let mut region = vec![vec![false; width]; height];
+    let target = (height * width * rng.range(30..=60) as usize / 100).max(1);
+
+    let start = [rng.index(height), rng.index(width)];
+
+    let mut frontier = vec![start];
+    let mut len = 0;
+
+    while len < target && !frontier.is_empty() {
+        let cell = frontier.swap_remove(rng.index(frontier.len()));
+
+        if region[cell[0]][cell[1]] || (len > 0 && !can_grow(&region, cell)) {
+            continue;
+        }
+
+        if completes_block(&region, cell) && !rng.chance(fill) {
+            continue;
+        }
+
+        region[cell[0]][cell[1]] = true;
+        len += 1;
+
+        for [dy, dx] in [[-1, 0], [1, 0], [0, -1], [0, 1]] {
+            let (Some(y), Some(x)) = (
+                cell[0].checked_add_signed(dy).filter(|&y| y < height),
+                cell[1].checked_add_signed(dx).filter(|&x| x < width),
+            ) else {
+                continue;
+            };
+
+            if !region[y][x] {
+                frontier.push([y, x]);
+            }
+        }
+    }
+
+    // each boundary point joins exactly two boundary edges
+    let mut joined = vec![vec![Vec::new(); width + 1]; height + 1];
+
+    for (y, row) in region.iter().enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|(_, &cell)| cell) {
+            let outside = |dy: isize, dx: isize| !is_in(&region, y as isize + dy, x as isize + dx);
+
+            let sides = [
+                (outside(-1, 0), [y, x], [y, x + 1]),
+                (outside(1, 0), [y + 1, x], [y + 1, x + 1]),
+                (outside(0, -1), [y, x], [y + 1, x]),
+                (outside(0, 1), [y, x + 1], [y + 1, x + 1]),
+            ];
+
+            for (_, a, b) in sides.into_iter().filter(|(outside, ..)| *outside) {
+                joined[a[0]][a[1]].push(b);
+                joined[b[0]][b[1]].push(a);
+            }
+        }
+    }
+
+    // the top left corner of the first cell is on the boundary, as the cell above it isn't in the
+    // region
+    let first = (0..height)
+        .flat_map(|y| (0..width).map(move |x| [y, x]))
+        .find(|&[y, x]| region[y][x])
+        .unwrap();
+    let mut points = vec![first];
+    let mut prev = first;
+    let mut curr = joined[first[0]][first[1]][0];
+
+    while curr != first {
+        points.push(curr);
+
+        let next = *joined[curr[0]][curr[1]]
+            .iter()
+            .find(|&&next| next != prev)
+            .unwrap();
+
+        prev = curr;
+        curr = next;
+    }
+
+    points
+}
+
+fn is_in(region: &[Vec<bool>], y: isize, x: isize) -> bool {
+    usize::try_from(y)
+        .ok()
+        .and_then(|y| region.get(y))
+        .zip(usize::try_from(x).ok())
+        .and_then(|(row, x)| row.get(x))
+        .copied()
+        .unwrap_or(false)
+}
+
+/// Returns true if adding `cell` to `region` keeps its boundary a single simple loop. That's the
+/// case when the region's cells among the eight surrounding `cell` are a single run, which
+/// includes a cell sharing a side with it.
+fn can_grow(region: &[Vec<bool>], cell: [usize; 2]) -> bool {
+    const RING: [[isize; 2]; 8] = [
+        [-1, 0],
+        [-1, 1],
+        [0, 1],
+        [1, 1],
+        [1, 0],
+        [1, -1],
+        [0, -1],
+        [-1, -1],
+    ];
+
+    let ring = RING.map(|[dy, dx]| is_in(region, cell[0] as isize + dy, cell[1] as isize + dx));
+
+    let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+    let touches_side = (0..8).step_by(2).any(|i| ring[i]);
+
+    runs == 1 && touches_side
+}
+
+/// Returns true if adding `cell` to `region` would fill a 2 by 2 block
+fn completes_block(region: &[Vec<bool>], cell: [usize; 2]) -> bool {
+    let [y, x] = cell.map(|c| c as isize);
+
+    [[-1, -1], [-1, 1], [1, -1], [1, 1]].iter().any(|[dy, dx]| {
+        is_in(region, y + dy, x) && is_in(region, y, x + dx) && is_in(region, y + dy, x + dx)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn seeds_repeat() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        let mut seen = HashSet::new();
+
+        for _ in 0..1000 {
+            let n = rng.range(-3..=3);
+            assert!((-3..=3).contains(&n));
+            seen.insert(n);
+        }
+
+        assert_eq!(seen.len(), 7);
+        assert_eq!(rng.range(i64::MIN..=i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn loops_are_simple() {
+        for seed in 0..20 {
+            let points = random_loop(&mut Rng::new(seed), 12, 9, seed as f64 / 20.0);
+            let unique: HashSet<_> = points.iter().collect();
+
+            assert_eq!(unique.len(), points.len());
+            assert!(points.iter().all(|&[y, x]| y <= 12 && x <= 9));
+
+            for (i, a) in points.iter().enumerate() {
+                let b = points[(i + 1) % points.len()];
+                assert_eq!(a[0].abs_diff(b[0]) + a[1].abs_diff(b[1]), 1);
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+/// One of the four directions on a grid, where up is towards the first row.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// Every direction, clockwise from up
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Right, Dir::Down, Dir::Left];
+
+    /// Returns the `[row, column]` offset of a single step in this direction.
+    pub fn offset(self) -> [isize; 2] {
+        match self {
+            Dir::Up => [-1, 0],
+            Dir::Right => [0, 1],
+            Dir::Down => [1, 0],
+            Dir::Left => [0, -1],
+        }
+    }
+
+    /// Returns the direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Returns the direction a quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn is_vertical(self) -> bool {
+        matches!(self, Dir::Up | Dir::Down)
+    }
+
+    /// Returns the direction after reflecting off a `/` mirror.
+    pub fn reflect_slash(self) -> Self {
+        match self {
+            Dir::Up => Dir::Right,
+            Dir::Right => Dir::Up,
+            Dir::Down => Dir::Left,
+            Dir::Left => Dir::Down,
+        }
+    }
+
+    /// Returns the direction after reflecting off a `\` mirror.
+    pub fn reflect_backslash(self) -> Self {
+        match self {
+            Dir::Up => Dir::Left,
+            Dir::Left => Dir::Up,
+            Dir::Down => Dir::Right,
+            Dir::Right => Dir::Down,
+        }
+    }
+}
+
+/// A cell on a grid, as its row `y` counting down from the top and its column `x`.
+///
+/// Points are ordered row by row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub y: usize,
+    pub x: usize,
+}
+
+impl Point {
+    pub const fn new(y: usize, x: usize) -> Self {
+        Self { y, x }
+    }
+
+    /// Returns the point `[row, column]` `offset` away, unless it's above the first row or left
+    /// of the first column.
+    pub fn offset(self, offset: [isize; 2]) -> Option<Self> {
+        Some(Self {
+            y: self.y.checked_add_signed(offset[0])?,
+            x: self.x.checked_add_signed(offset[1])?,
+        })
+    }
+
+    /// Returns the adjacent point in direction `dir`, unless it's above the first row or left of
+    /// the first column. [`Grid::step`](crate::Grid::step) also checks the far edges of a grid.
+    pub fn step(self, dir: Dir) -> Option<Self> {
+        self.offset(dir.offset())
+    }
+
+    /// Returns the number of steps between `self` and `other`, without moving diagonally.
+    pub fn manhattan(self, other: Self) -> usize {
+        self.y.abs_diff(other.y) + self.x.abs_diff(other.x)
+    }
+
+    /// Returns the direction of an adjacent point `other`.
+    pub fn dir_to(self, other: Self) -> Option<Dir> {
+        Dir::ALL
+            .into_iter()
+            .find(|&dir| self.step(dir) == Some(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns() {
+        for dir in Dir::ALL {
+            assert_eq!(dir.turn_right().turn_left(), dir);
+            assert_eq!(dir.turn_right().turn_right(), dir.reverse());
+            assert_eq!(dir.reflect_slash().reflect_slash(), dir);
+            assert_eq!(dir.reflect_backslash().reflect_backslash(), dir);
+            assert_eq!(dir.reflect_slash(), dir.reflect_backslash().reverse());
+            assert_ne!(dir.is_vertical(), dir.turn_left().is_vertical());
+        }
+
+        assert_eq!(Dir::Up.turn_right(), Dir::Right);
+        assert_eq!(Dir::Up.turn_left(), Dir::Left);
+        assert_eq!(Dir::Right.reflect_slash(), Dir::Up);
+    }
+
+    #[test]
+    fn steps() {
+        let point = Point::new(0, 3);
+
+        assert_eq!(point.step(Dir::Up), None);
+        assert_eq!(point.step(Dir::Left), Some(Point::new(0, 2)));
+        assert_eq!(point.offset([2, -3]), Some(Point::new(2, 0)));
+        assert_eq!(point.manhattan(Point::new(2, 0)), 5);
+        assert_eq!(point.dir_to(Point::new(1, 3)), Some(Dir::Down));
+        assert_eq!(point.dir_to(Point::new(1, 4)), None);
+
+        for dir in Dir::ALL {
+            let next = Point::new(5, 5).step(dir).unwrap();
+            assert_eq!(next.step(dir.reverse()), Some(Point::new(5, 5)));
+        }
+    }
+}
@@ -0,0 +1,329 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{lines, unexpected_end, Dir, Line, ParseError, Point};
+
+/// Offsets to every cell touching a cell, including diagonally
+const SURROUNDING: [[isize; 2]; 8] = [
+    [-1, -1],
+    [-1, 0],
+    [-1, 1],
+    [0, -1],
+    [0, 1],
+    [1, -1],
+    [1, 0],
+    [1, 1],
+];
+
+/// A rectangular grid of cells, stored row by row and indexed by [`Point`]s.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Returns a grid of `height` rows of `width` columns, with every cell set to `cell`.
+    pub fn new(width: usize, height: usize, cell: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![cell; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Parses every line of `input` as a row, using `parse_cell` to parse each character.
+    ///
+    /// Characters which `parse_cell` rejects are reported as `expected`.
+    pub fn parse<F>(input: &str, expected: impl Display, parse_cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut lines = lines(input);
+        let grid = Self::parse_lines(input, &mut lines, expected, parse_cell)?;
+
+        match lines.next() {
+            Some(line) => Err(line.error_at(0, "the end of the input")),
+            None => Ok(grid),
+        }
+    }
+
+    /// Parses rows from `lines` up to the next empty line, which is consumed, or the end of
+    /// `input`. This allows for inputs with several grids separated by empty lines.
+    pub fn parse_lines<'a, I, F>(
+        input: &str,
+        lines: &mut I,
+        expected: impl Display,
+        mut parse_cell: F,
+    ) -> Result<Self, ParseError>
+    where
+        I: Iterator<Item = Line<'a>>,
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in lines {
+            if line.text.is_empty() {
+                if height == 0 {
+                    return Err(line.error_at(0, &expected));
+                }
+
+                break;
+            }
+
+            let start = cells.len();
+
+            for (i, char) in line.text.char_indices() {
+                cells.push(parse_cell(char).ok_or_else(|| line.error_at(i, &expected))?);
+            }
+
+            let len = cells.len() - start;
+            let width = *width.get_or_insert(len);
+
+            if len != width {
+                let offset = line
+                    .text
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.text.len(), |(i, _)| i);
+
+                return Err(line.error_at(offset, format_args!("{width} columns")));
+            }
+
+            height += 1;
+        }
+
+        let width = width.ok_or_else(|| unexpected_end(input, &expected))?;
+
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns true if `point` is within the grid.
+    pub fn contains(&self, point: Point) -> bool {
+        point.y < self.height && point.x < self.width
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// Returns the point `[row, column]` `offset` away from `point`, if it's within the grid.
+    pub fn offset(&self, point: Point, offset: [isize; 2]) -> Option<Point> {
+        point.offset(offset).filter(|&point| self.contains(point))
+    }
+
+    /// Returns the adjacent point in direction `dir`, if it's within the grid.
+    pub fn step(&self, point: Point, dir: Dir) -> Option<Point> {
+        self.offset(point, dir.offset())
+    }
+
+    /// Returns the points sharing a side with `point`, clockwise from above it.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Dir::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(point, dir))
+    }
+
+    /// Returns every point touching `point`, including diagonally, row by row.
+    pub fn neighbours_with_diagonals(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&offset| self.offset(point, offset))
+    }
+
+    /// Returns the cells of row `y`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// Returns the cells of column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        assert!(x < self.width, "column {x} is outside the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> + '_ {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns every point in the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(y, x)))
+    }
+
+    /// Returns every cell in the grid, row by row.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.cells.iter_mut()
+    }
+
+    /// Returns the point of the first cell which matches `predicate`, row by row.
+    pub fn position<F>(&self, predicate: F) -> Option<Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let i = self.cells.iter().position(predicate)?;
+        Some(Point::new(i / self.width, i % self.width))
+    }
+
+    /// Returns a grid of the same size, with `f` applied to each cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        let (width, height) = (self.width, self.height);
+
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {width}x{height} grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the {width}x{height} grid"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |char| char.to_digit(10))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456\n").unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(1, 0)], 4);
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).collect::<Vec<_>>(), [&3, &6]);
+        assert_eq!(grid.position(|&cell| cell == 5), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(digits("12\n3x\n"), Err(ParseError::new(2, 2, "a digit")));
+        assert_eq!(digits("12\n345\n"), Err(ParseError::new(2, 3, "2 columns")));
+        assert_eq!(digits("12\n3\n"), Err(ParseError::new(2, 2, "2 columns")));
+        assert_eq!(
+            digits("12\n\n34\n"),
+            Err(ParseError::new(3, 1, "the end of the input"))
+        );
+        assert_eq!(digits("\n12\n"), Err(ParseError::new(1, 1, "a digit")));
+        assert_eq!(digits(""), Err(ParseError::new(1, 1, "a digit")));
+    }
+
+    #[test]
+    fn parse_several() {
+        let input = "12\n34\n\n5\n6\n7\n";
+        let mut lines = lines(input);
+
+        let a = Grid::parse_lines(input, &mut lines, "a digit", |c| c.to_digit(10)).unwrap();
+        let b = Grid::parse_lines(input, &mut lines, "a digit", |c| c.to_digit(10)).unwrap();
+
+        assert_eq!(a.rows().collect::<Vec<_>>(), [[1, 2], [3, 4]]);
+        assert_eq!(b.column(0).copied().collect::<Vec<_>>(), [5, 6, 7]);
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 2, ());
+        let points = |points: &[[usize; 2]]| -> Vec<_> {
+            points.iter().map(|&[y, x]| Point::new(y, x)).collect()
+        };
+
+        assert_eq!(
+            grid.neighbours(Point::new(0, 0)).collect::<Vec<_>>(),
+            points(&[[0, 1], [1, 0]])
+        );
+        assert_eq!(
+            grid.neighbours(Point::new(1, 1)).collect::<Vec<_>>(),
+            points(&[[0, 1], [1, 2], [1, 0]])
+        );
+        assert_eq!(
+            grid.neighbours_with_diagonals(Point::new(0, 2))
+                .collect::<Vec<_>>(),
+            points(&[[0, 1], [1, 1], [1, 2]])
+        );
+        assert_eq!(grid.neighbours_with_diagonals(Point::new(1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn bounds() {
+        let mut grid = Grid::new(2, 3, 0);
+
+        assert_eq!(grid.get(Point::new(2, 1)), Some(&0));
+        assert_eq!(grid.get(Point::new(1, 2)), None);
+        assert_eq!(grid.offset(Point::new(0, 1), [-1, 0]), None);
+        assert_eq!(
+            grid.offset(Point::new(0, 1), [2, -1]),
+            Some(Point::new(2, 0))
+        );
+        assert_eq!(grid.step(Point::new(2, 1), Dir::Down), None);
+        assert_eq!(
+            grid.step(Point::new(2, 1), Dir::Left),
+            Some(Point::new(2, 0))
+        );
+
+        grid[Point::new(2, 1)] = 5;
+        assert_eq!(grid.iter().sum::<i32>(), 5);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::{self, ErrorKind, Read},
+};
+
+/// The path which reads the input from stdin instead of a file
+pub const STDIN_PATH: &str = "-";
+
+/// Reads a puzzle input from the file at `path`, or from stdin if `path` is `-`.
+pub fn read_input(path: &str) -> Result<String, InputError> {
+    let result = if path == STDIN_PATH {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        fs::read_to_string(path)
+    };
+
+    result.map_err(|source| InputError {
+        path: path.to_owned(),
+        source,
+    })
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    path: String,
+    source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.path == STDIN_PATH {
+            write!(f, "failed to read input from stdin: {}", self.source)
+        } else if self.source.kind() == ErrorKind::NotFound {
+            write!(f, "input file `{}` not found", self.path)
+        } else {
+            write!(
+                f,
+                "failed to read input file `{}`: {}",
+                self.path, self.source
+            )
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
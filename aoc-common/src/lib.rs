@@ -0,0 +1,23 @@
+//! Code shared between the daily solutions and the `aoc` runner.
+
+mod cli;
+mod cycle;
+mod fuzz;
+mod generate;
+mod geometry;
+mod grid;
+mod input;
+mod parse;
+mod search;
+mod solution;
+
+pub use cli::{day_main, DEFAULT_INPUT_PATH};
+pub use cycle::{find_cycle, Cycle};
+pub use fuzz::fuzz_parse;
+pub use generate::{generate, random_loop, Generator, Rng};
+pub use geometry::{Dir, Point};
+pub use grid::Grid;
+pub use input::{read_input, InputError, STDIN_PATH};
+pub use parse::{lines, unexpected_end, Line, ParseError};
+pub use search::{astar, bfs, dijkstra, Path};
+pub use solution::{solve, Answer, MaybeAnswer, MaybeDisplay, Part, Run, Solution};
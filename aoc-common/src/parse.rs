@@ -0,0 +1,127 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// An error in a puzzle input, located by its 1-based line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// A description of what was expected at the location, e.g. "a number"
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Display) -> Self {
+        Self {
+            line,
+            column,
+            expected: expected.to_string(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Returns an iterator over the lines of `input`, which can locate errors within themselves.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Returns an error for an input which ended while `expected` was still expected.
+pub fn unexpected_end(input: &str, expected: impl Display) -> ParseError {
+    ParseError::new(input.lines().count() + 1, 1, expected)
+}
+
+/// A single line of a puzzle input.
+///
+/// The methods which take a `&str` expect it to be a slice of [`Line::text`], so that errors can
+/// be located by its position in the line.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    /// The 0-based line index
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Returns an error located at the start of `at`.
+    pub fn error(&self, at: &str, expected: impl Display) -> ParseError {
+        let offset = (at.as_ptr() as usize)
+            .wrapping_sub(self.text.as_ptr() as usize)
+            .min(self.text.len());
+
+        self.error_at(offset, expected)
+    }
+
+    /// Returns an error located just past the end of `s`.
+    pub fn error_after(&self, s: &str, expected: impl Display) -> ParseError {
+        self.error(&s[s.len()..], expected)
+    }
+
+    /// Returns an error located at byte `offset` of the line.
+    pub fn error_at(&self, offset: usize, expected: impl Display) -> ParseError {
+        let column = self
+            .text
+            .get(..offset)
+            .map_or(offset, |prefix| prefix.chars().count());
+
+        ParseError::new(self.index + 1, column + 1, expected)
+    }
+
+    /// Parses `token`, or returns an error describing it as `expected`.
+    pub fn parse<T: FromStr>(&self, token: &str, expected: impl Display) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    /// Returns the next token from `tokens`, or an error at the end of the line.
+    pub fn next<I>(&self, tokens: &mut I, expected: impl Display) -> Result<&'a str, ParseError>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        tokens
+            .next()
+            .ok_or_else(|| self.error_at(self.text.len(), expected))
+    }
+
+    /// Parses the next token from `tokens`.
+    pub fn parse_next<T, I>(&self, tokens: &mut I, expected: impl Display) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        I: Iterator<Item = &'a str>,
+    {
+        let token = self.next(tokens, &expected)?;
+        self.parse(token, expected)
+    }
+
+    /// Splits `s` around the first occurrence of `delimiter`.
+    pub fn split_once(
+        &self,
+        s: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        s.split_once(delimiter)
+            .ok_or_else(|| self.error_after(s, format_args!("`{delimiter}`")))
+    }
+
+    /// Removes `prefix` from the start of `s`.
+    pub fn strip_prefix(&self, s: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        s.strip_prefix(prefix)
+            .ok_or_else(|| self.error(s, format_args!("`{prefix}`")))
+    }
+}
@@ -0,0 +1,310 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The cheapest route found from a start to a goal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// Every state along the route, from the start to the goal inclusive
+    pub states: Vec<S>,
+}
+
+/// Every state reached so far, with the index of the state it was reached from and its cost.
+/// States are stored once and referred to by index everywhere else.
+struct Visited<S, C> {
+    states: Vec<(S, Option<usize>, C)>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Visited<S, C> {
+    fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// Records reaching `state` from `parent` at `cost`, returning its index. Returns `None` if
+    /// it has already been reached at most as cheaply, as decided by `improves`.
+    fn insert<F>(&mut self, state: S, parent: Option<usize>, cost: C, improves: F) -> Option<usize>
+    where
+        F: FnOnce(C, C) -> bool,
+    {
+        match self.indices.get(&state) {
+            Some(&i) => {
+                if !improves(cost, self.states[i].2) {
+                    return None;
+                }
+
+                self.states[i] = (state, parent, cost);
+                Some(i)
+            }
+            None => {
+                let i = self.states.len();
+                self.indices.insert(state.clone(), i);
+                self.states.push((state, parent, cost));
+                Some(i)
+            }
+        }
+    }
+
+    fn path(&self, mut i: usize) -> Path<S, C> {
+        let cost = self.states[i].2;
+        let mut states = vec![self.states[i].0.clone()];
+
+        while let Some(parent) = self.states[i].1 {
+            states.push(self.states[parent].0.clone());
+            i = parent;
+        }
+
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+/// Returns the route with the fewest steps from any of `starts` to a state matching `is_goal`,
+/// where `successors` returns the states one step away from a state.
+pub fn bfs<S, I, F, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut is_goal: G,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut queue: VecDeque<_> = starts
+        .into_iter()
+        .filter_map(|start| visited.insert(start, None, 0, |_, _| false))
+        .collect();
+
+    while let Some(i) = queue.pop_front() {
+        let (state, _, steps) = &visited.states[i];
+
+        if is_goal(state) {
+            return Some(visited.path(i));
+        }
+
+        let steps = steps + 1;
+
+        for next in successors(state) {
+            queue.extend(visited.insert(next, Some(i), steps, |_, _| false));
+        }
+    }
+
+    None
+}
+
+/// Returns the cheapest route from any of `starts` to a state matching `is_goal`, where
+/// `successors` returns the states one step away from a state along with the cost of the step.
+pub fn dijkstra<S, C, I, F, G>(
+    starts: impl IntoIterator<Item = S>,
+    successors: F,
+    is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    F: FnMut(&S) -> I,
+    G: FnMut(&S) -> bool,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// As [`dijkstra`], but states which `heuristic` estimates to be closer to a goal are searched
+/// first. The route is only guaranteed to be the cheapest if `heuristic` never overestimates the
+/// remaining cost, and never decreases by more than the cost of a step.
+pub fn astar<S, C, I, F, H, G>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (S, C)>,
+    F: FnMut(&S) -> I,
+    H: FnMut(&S) -> C,
+    G: FnMut(&S) -> bool,
+{
+    let mut visited = Visited::new();
+    let mut open = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+
+        if let Some(index) = visited.insert(start, None, C::default(), |_, _| false) {
+            open.push(Open {
+                estimate,
+                cost: C::default(),
+                index,
+            });
+        }
+    }
+
+    while let Some(Open { cost, index, .. }) = open.pop() {
+        let (state, _, best) = &visited.states[index];
+
+        if cost > *best {
+            // a cheaper route here was found after this one was queued
+            continue;
+        }
+
+        if is_goal(state) {
+            return Some(visited.path(index));
+        }
+
+        for (next, step) in successors(state) {
+            let cost = cost + step;
+            let estimate = cost + heuristic(&next);
+
+            if let Some(next) = visited.insert(next, Some(index), cost, |new, old| new < old) {
+                open.push(Open {
+                    estimate,
+                    cost,
+                    index: next,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// A state waiting to be searched, ordered so that the lowest estimate is popped first from a
+/// max-heap
+struct Open<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> PartialEq for Open<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Open<C> {}
+
+impl<C: Ord> PartialOrd for Open<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> Ord for Open<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Point};
+
+    /// A maze of walls with a cost to enter each open cell
+    const MAZE: &str = "\
+        1#111\n\
+        1#9#1\n\
+        11111\n";
+
+    fn maze() -> Grid<Option<u32>> {
+        Grid::parse(MAZE, "a digit or `#`", |char| match char {
+            '#' => Some(None),
+            _ => char.to_digit(10).map(Some),
+        })
+        .unwrap()
+    }
+
+    fn successors(maze: &Grid<Option<u32>>, point: Point) -> Vec<(Point, u32)> {
+        maze.neighbours(point)
+            .filter_map(|next| Some((next, maze[next]?)))
+            .collect()
+    }
+
+    #[test]
+    fn shortest_paths() {
+        let maze = maze();
+        let goal = Point::new(0, 4);
+
+        let path = bfs(
+            [Point::new(0, 0)],
+            |&point| successors(&maze, point).into_iter().map(|(next, _)| next),
+            |&point| point == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 8);
+        assert_eq!(path.states.len(), 9);
+        assert_eq!(path.states[4], Point::new(2, 2));
+
+        let path = dijkstra(
+            [Point::new(0, 0)],
+            |&point| successors(&maze, point),
+            |&point| point == goal,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 8);
+
+        let path = dijkstra(
+            [Point::new(0, 0)],
+            |&point| successors(&maze, point),
+            |&point| point == Point::new(1, 2),
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 13);
+        assert_eq!(path.states.last(), Some(&Point::new(1, 2)));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let maze = maze();
+
+        for goal in maze.points().filter(|&point| maze[point].is_some()) {
+            let expected = dijkstra(
+                [Point::new(0, 0)],
+                |&point| successors(&maze, point),
+                |&point| point == goal,
+            );
+
+            let path = astar(
+                [Point::new(0, 0)],
+                |&point| successors(&maze, point),
+                |&point| point.manhattan(goal) as u32,
+                |&point| point == goal,
+            );
+
+            assert_eq!(path.map(|path| path.cost), expected.map(|path| path.cost));
+        }
+    }
+
+    #[test]
+    fn unreachable() {
+        let path = bfs(
+            [0],
+            |&n| [n + 2].into_iter().filter(|&n| n < 10),
+            |&n| n == 5,
+        );
+        assert_eq!(path, None);
+
+        let path = bfs(
+            [3, 0],
+            |&n| [n + 2].into_iter().filter(|&n| n < 10),
+            |&n| n == 5,
+        );
+        assert_eq!(path.map(|path| path.states), Some(vec![3, 5]));
+    }
+}
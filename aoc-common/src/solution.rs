@@ -0,0 +1,197 @@
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::ParseError;
+
+/// A solution to one day's puzzle.
+pub trait Solution {
+    /// The parsed puzzle input, which may borrow from the input text.
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay;
+
+    fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, found `{s}`")),
+        }
+    }
+}
+
+/// An answer which some valid inputs don't have, along with the reason it's missing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaybeAnswer<T> {
+    pub answer: Option<T>,
+    pub reason: &'static str,
+}
+
+impl<T> MaybeAnswer<T> {
+    pub fn new(answer: Option<T>, reason: &'static str) -> Self {
+        Self { answer, reason }
+    }
+}
+
+/// The answer to a part, which is either displayed as is or is a [`MaybeAnswer`].
+pub trait MaybeDisplay {
+    /// Returns the displayed answer, or the reason there isn't one.
+    fn display(&self) -> Result<String, &'static str>;
+}
+
+impl<T: Display> MaybeDisplay for T {
+    fn display(&self) -> Result<String, &'static str> {
+        Ok(self.to_string())
+    }
+}
+
+impl<T: Display> MaybeDisplay for MaybeAnswer<T> {
+    fn display(&self) -> Result<String, &'static str> {
+        self.answer
+            .as_ref()
+            .map(ToString::to_string)
+            .ok_or(self.reason)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Answer {
+    pub part: Part,
+    /// The displayed answer, or `None` if the input has no answer for the part
+    pub value: Option<String>,
+    /// Why there's no answer, when `value` is `None`
+    pub reason: Option<&'static str>,
+    pub time: Duration,
+}
+
+/// Displays the answer, or `none` along with the reason when it's missing.
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.value, self.reason) {
+            (Some(value), _) => write!(f, "{value}"),
+            (None, Some(reason)) => write!(f, "none ({reason})"),
+            (None, None) => write!(f, "none"),
+        }
+    }
+}
+
+/// The answers from a single run of a solution.
+#[derive(Clone, Debug)]
+pub struct Run {
+    pub parse_time: Duration,
+    pub answers: Vec<Answer>,
+}
+
+/// Parses `input` and solves each of `parts`, timing every step.
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Run, ParseError> {
+    let start = Instant::now();
+    let input = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+
+            let display = match part {
+                Part::One => S::part_one(&input).display(),
+                Part::Two => S::part_two(&input).display(),
+            };
+
+            let time = start.elapsed();
+
+            match display {
+                Ok(value) => Answer {
+                    part,
+                    value: Some(value),
+                    reason: None,
+                    time,
+                },
+                Err(reason) => Answer {
+                    part,
+                    value: None,
+                    reason: Some(reason),
+                    time,
+                },
+            }
+        })
+        .collect();
+
+    Ok(Run {
+        parse_time,
+        answers,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Halves a number, which only even numbers have an answer for in part two
+    struct Halve;
+
+    impl Solution for Halve {
+        type Input<'a> = u32;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            input.parse().map_err(|_| ParseError::new(1, 1, "a number"))
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> impl MaybeDisplay {
+            input / 2
+        }
+
+        fn part_two(input: &Self::Input<'_>) -> impl MaybeDisplay {
+            MaybeAnswer::new((input % 2 == 0).then_some(input / 2), "an odd number")
+        }
+    }
+
+    #[test]
+    fn missing_answers() {
+        let run = solve::<Halve>("8", &Part::ALL).unwrap();
+        let values: Vec<_> = run.answers.iter().map(|answer| &answer.value).collect();
+
+        assert_eq!(values, [&Some("4".to_owned()), &Some("4".to_owned())]);
+        assert_eq!(run.answers[1].reason, None);
+
+        let run = solve::<Halve>("7", &Part::ALL).unwrap();
+
+        assert_eq!(run.answers[0].value.as_deref(), Some("3"));
+        assert_eq!(run.answers[1].value, None);
+        assert_eq!(run.answers[1].reason, Some("an odd number"));
+        assert_eq!(run.answers[1].to_string(), "none (an odd number)");
+    }
+}
@@ -0,0 +1,233 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+};
+
+use aoc_common::Part;
+use serde::Serialize;
+
+/// The answers manifest used when none is given on the command line
+pub const DEFAULT_MANIFEST_PATH: &str = "answers.toml";
+
+/// Known answers, keyed by day and then by input path.
+///
+/// Stored as TOML, with a table for each day and input:
+///
+/// ```toml
+/// [5."aoc-05/input"]
+/// 1 = "35"
+/// 2 = "46"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Manifest {
+    days: BTreeMap<u8, BTreeMap<String, BTreeMap<Part, String>>>,
+}
+
+/// The raw TOML representation of a [`Manifest`]
+type Tables = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+/// The outcome of checking an answer against the manifest
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "verdict", rename_all = "lowercase")]
+pub enum Verdict<'a> {
+    Pass,
+    Fail { expected: &'a str },
+    Missing,
+}
+
+impl Display for Verdict<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { expected } => write!(f, "FAIL, expected {expected}"),
+            Verdict::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+impl Manifest {
+    /// Reads the manifest at `path`. A missing file is an empty manifest.
+    pub fn load(path: &str) -> Result<Self, ManifestError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(ManifestError::new(path, err)),
+        };
+
+        Self::parse(&text).map_err(|reason| ManifestError::new(path, reason))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), ManifestError> {
+        fs::write(path, self.to_string()).map_err(|err| ManifestError::new(path, err))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let tables: Tables = toml::from_str(text).map_err(|err| err.message().to_owned())?;
+        let mut days = BTreeMap::new();
+
+        for (day, inputs) in tables {
+            let day = day
+                .parse()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("expected a day from 1 to 25, found `{day}`"))?;
+
+            let inputs = inputs
+                .into_iter()
+                .map(|(input, answers)| {
+                    let answers = answers
+                        .into_iter()
+                        .map(|(part, value)| Ok((part.parse()?, value)))
+                        .collect::<Result<_, String>>()?;
+
+                    Ok((input, answers))
+                })
+                .collect::<Result<_, String>>()?;
+
+            days.insert(day, inputs);
+        }
+
+        Ok(Self { days })
+    }
+
+    /// Returns every day and input path with recorded answers
+    pub fn inputs(&self) -> impl Iterator<Item = (u8, &str)> {
+        self.days
+            .iter()
+            .flat_map(|(&day, inputs)| inputs.keys().map(move |input| (day, input.as_str())))
+    }
+
+    pub fn verify(&self, day: u8, input: &str, part: Part, value: &str) -> Verdict<'_> {
+        match self.answer(day, input, part) {
+            Some(expected) if expected == value => Verdict::Pass,
+            Some(expected) => Verdict::Fail { expected },
+            None => Verdict::Missing,
+        }
+    }
+
+    pub fn answer(&self, day: u8, input: &str, part: Part) -> Option<&str> {
+        self.days
+            .get(&day)?
+            .get(input)?
+            .get(&part)
+            .map(String::as_str)
+    }
+
+    pub fn record(&mut self, day: u8, input: &str, part: Part, value: &str) {
+        self.days
+            .entry(day)
+            .or_default()
+            .entry(input.to_owned())
+            .or_default()
+            .insert(part, value.to_owned());
+    }
+}
+
+impl Display for Manifest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tables: Tables = self
+            .days
+            .iter()
+            .map(|(day, inputs)| {
+                let inputs = inputs
+                    .iter()
+                    .map(|(input, answers)| {
+                        let answers = answers
+                            .iter()
+                            .map(|(part, value)| (part.to_string(), value.clone()))
+                            .collect();
+
+                        (input.clone(), answers)
+                    })
+                    .collect();
+
+                (day.to_string(), inputs)
+            })
+            .collect();
+
+        let text = toml::to_string(&tables).map_err(|_| fmt::Error)?;
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug)]
+pub struct ManifestError {
+    path: String,
+    reason: String,
+}
+
+impl ManifestError {
+    fn new(path: &str, reason: impl Display) -> Self {
+        Self {
+            path: path.to_owned(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid answers manifest `{}`: {}",
+            self.path, self.reason
+        )
+    }
+}
+
+impl Error for ManifestError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+[5."aoc-05/input"]
+1 = "35"
+
+[5."aoc-05/example"]
+1 = "35"
+2 = "46"
+"#;
+
+    #[test]
+    fn verify_answers() {
+        let manifest = Manifest::parse(MANIFEST).unwrap();
+
+        assert_eq!(
+            manifest.verify(5, "aoc-05/example", Part::Two, "46"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            manifest.verify(5, "aoc-05/example", Part::Two, "56"),
+            Verdict::Fail { expected: "46" }
+        );
+        assert_eq!(
+            manifest.verify(5, "aoc-05/input", Part::Two, "46"),
+            Verdict::Missing
+        );
+        assert_eq!(
+            manifest.verify(6, "aoc-05/input", Part::One, "35"),
+            Verdict::Missing
+        );
+    }
+
+    #[test]
+    fn record_round_trips() {
+        let mut manifest = Manifest::parse(MANIFEST).unwrap();
+        manifest.record(5, "aoc-05/input", Part::Two, "46");
+        manifest.record(12, "aoc-12/input", Part::One, "21");
+
+        assert_eq!(Manifest::parse(&manifest.to_string()), Ok(manifest));
+    }
+
+    #[test]
+    fn invalid_keys() {
+        assert!(Manifest::parse("[26.input]\n1 = \"1\"").is_err());
+        assert!(Manifest::parse("[day5.input]\n1 = \"1\"").is_err());
+        assert!(Manifest::parse("[5.input]\n3 = \"1\"").is_err());
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    str::FromStr,
+    time::Duration,
+};
+
+use aoc_common::{ParseError, Part, Run};
+use serde::{Deserialize, Serialize};
+
+/// A step of a solution which is timed separately
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {part}"),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("part ") {
+            Some(part) => Ok(Step::Part(part.parse()?)),
+            None if s == "parse" => Ok(Step::Parse),
+            None => Err(format!(
+                "expected `parse`, `part 1` or `part 2`, found `{s}`"
+            )),
+        }
+    }
+}
+
+/// Wall time statistics for a step over several runs
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    /// Returns `None` if there are no times.
+    pub fn new(times: &[Duration]) -> Option<Self> {
+        let mut times = times.to_vec();
+        times.sort_unstable();
+
+        let runs = times.len();
+        let min = *times.first()?;
+
+        let median = if runs.is_multiple_of(2) {
+            (times[runs / 2 - 1] + times[runs / 2]) / 2
+        } else {
+            times[runs / 2]
+        };
+
+        let mean = times.iter().sum::<Duration>() / runs as u32;
+
+        Some(Self {
+            runs,
+            min,
+            median,
+            mean,
+        })
+    }
+
+    /// Returns the relative change in the median time from `baseline`, e.g. `0.1` for 10% slower
+    pub fn change(&self, baseline: &Stats) -> f64 {
+        self.median.as_secs_f64() / baseline.median.as_secs_f64() - 1.0
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?}",
+            self.min, self.median, self.mean
+        )
+    }
+}
+
+/// Runs `solve` on `input` `runs` times, and returns the statistics for each step.
+pub fn measure(
+    solve: impl Fn(&str, &[Part]) -> Result<Run, ParseError>,
+    input: &str,
+    parts: &[Part],
+    runs: u32,
+) -> Result<BTreeMap<Step, Stats>, ParseError> {
+    let mut times: BTreeMap<Step, Vec<Duration>> = BTreeMap::new();
+
+    for _ in 0..runs {
+        let run = solve(input, parts)?;
+
+        times.entry(Step::Parse).or_default().push(run.parse_time);
+
+        for answer in run.answers {
+            times
+                .entry(Step::Part(answer.part))
+                .or_default()
+                .push(answer.time);
+        }
+    }
+
+    Ok(times
+        .into_iter()
+        .filter_map(|(step, times)| Some((step, Stats::new(&times)?)))
+        .collect())
+}
+
+/// Benchmark results, keyed by day and step.
+///
+/// Stored as TOML, with a table for each day and step and the times in nanoseconds:
+///
+/// ```toml
+/// [5.parse]
+/// runs = 10
+/// min_ns = 41300
+/// median_ns = 42950
+/// mean_ns = 44100
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    days: BTreeMap<u8, BTreeMap<Step, Stats>>,
+}
+
+/// The raw TOML representation of [`Stats`]
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RawStats {
+    runs: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+}
+
+/// The raw TOML representation of a [`Report`]
+type Tables = BTreeMap<String, BTreeMap<String, RawStats>>;
+
+impl Report {
+    pub fn load(path: &str) -> Result<Self, ReportError> {
+        let text = fs::read_to_string(path).map_err(|err| ReportError::new(path, err))?;
+        Self::parse(&text).map_err(|reason| ReportError::new(path, reason))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), ReportError> {
+        fs::write(path, self.to_string()).map_err(|err| ReportError::new(path, err))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let tables: Tables = toml::from_str(text).map_err(|err| err.message().to_owned())?;
+        let mut days = BTreeMap::new();
+
+        for (day, steps) in tables {
+            let day = day
+                .parse()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("expected a day from 1 to 25, found `{day}`"))?;
+
+            let steps = steps
+                .into_iter()
+                .map(|(step, raw)| {
+                    let stats = Stats {
+                        runs: raw.runs,
+                        min: Duration::from_nanos(raw.min_ns),
+                        median: Duration::from_nanos(raw.median_ns),
+                        mean: Duration::from_nanos(raw.mean_ns),
+                    };
+
+                    Ok((step.parse()?, stats))
+                })
+                .collect::<Result<_, String>>()?;
+
+            days.insert(day, steps);
+        }
+
+        Ok(Self { days })
+    }
+
+    pub fn get(&self, day: u8, step: Step) -> Option<&Stats> {
+        self.days.get(&day)?.get(&step)
+    }
+
+    pub fn insert(&mut self, day: u8, steps: BTreeMap<Step, Stats>) {
+        self.days.insert(day, steps);
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let nanos = |duration: Duration| duration.as_nanos().try_into().unwrap_or(u64::MAX);
+
+        let tables: Tables = self
+            .days
+            .iter()
+            .map(|(day, steps)| {
+                let steps = steps
+                    .iter()
+                    .map(|(step, stats)| {
+                        let raw = RawStats {
+                            runs: stats.runs,
+                            min_ns: nanos(stats.min),
+                            median_ns: nanos(stats.median),
+                            mean_ns: nanos(stats.mean),
+                        };
+
+                        (step.to_string(), raw)
+                    })
+                    .collect();
+
+                (day.to_string(), steps)
+            })
+            .collect();
+
+        let text = toml::to_string(&tables).map_err(|_| fmt::Error)?;
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug)]
+pub struct ReportError {
+    path: String,
+    reason: String,
+}
+
+impl ReportError {
+    fn new(path: &str, reason: impl Display) -> Self {
+        Self {
+            path: path.to_owned(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for ReportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "invalid benchmark results `{}`: {}",
+            self.path, self.reason
+        )
+    }
+}
+
+impl Error for ReportError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(times: &[u64]) -> Vec<Duration> {
+        times.iter().map(|&ms| Duration::from_millis(ms)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        assert_eq!(
+            Stats::new(&millis(&[5, 1, 3])),
+            Some(Stats {
+                runs: 3,
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(3),
+            })
+        );
+        assert_eq!(
+            Stats::new(&millis(&[8, 2, 4, 2])),
+            Some(Stats {
+                runs: 4,
+                min: Duration::from_millis(2),
+                median: Duration::from_millis(3),
+                mean: Duration::from_millis(4),
+            })
+        );
+        assert_eq!(Stats::new(&[]), None);
+    }
+
+    #[test]
+    fn change_from_baseline() {
+        let baseline = Stats::new(&millis(&[10])).unwrap();
+
+        assert!((Stats::new(&millis(&[12])).unwrap().change(&baseline) - 0.2).abs() < 1e-9);
+        assert!((Stats::new(&millis(&[5])).unwrap().change(&baseline) + 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn report_round_trips() {
+        let mut report = Report::default();
+        let stats = Stats::new(&[Duration::from_nanos(41_300), Duration::from_nanos(44_600)]);
+
+        report.insert(
+            5,
+            BTreeMap::from([
+                (Step::Parse, stats.unwrap()),
+                (Step::Part(Part::Two), stats.unwrap()),
+            ]),
+        );
+
+        assert_eq!(Report::parse(&report.to_string()), Ok(report));
+        assert!(
+            Report::parse("[5.part3]\nruns = 1\nmin_ns = 1\nmedian_ns = 1\nmean_ns = 1").is_err()
+        );
+    }
+}
@@ -0,0 +1,319 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    fs, io,
+    path::Path,
+};
+
+use aoc_common::Part;
+use ureq::Agent;
+
+use crate::{config::Config, submit::Outcome};
+
+/// A client for the Advent of Code site, authenticated by a session cookie.
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+    year: u16,
+}
+
+impl Client {
+    /// Returns `None` if the config has no session cookie.
+    pub fn new(config: &Config) -> Option<Self> {
+        let session = config.session.clone()?;
+
+        let user_agent = match &config.contact {
+            Some(contact) => format!("{} ({contact})", user_agent()),
+            None => user_agent(),
+        };
+
+        Some(Self {
+            agent: ureq::AgentBuilder::new().user_agent(&user_agent).build(),
+            base_url: config.base_url.trim_end_matches('/').to_owned(),
+            session,
+            year: config.year,
+        })
+    }
+
+    /// Fetches the puzzle input for `day`
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{day}/input", self.base_url, self.year);
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| ClientError::from_ureq(&url, err))?;
+
+        response.into_string().map_err(|err| ClientError::Io {
+            url,
+            reason: err.to_string(),
+        })
+    }
+
+    /// Saves the puzzle input for `day` to `path`, unless it has been downloaded there before.
+    pub fn download_input(&self, day: u8, path: &Path) -> Result<Download, ClientError> {
+        if path.exists() {
+            return Ok(Download::Cached);
+        }
+
+        let input = self.input(day)?;
+
+        // write to a temporary file first, so an interrupted download isn't mistaken for a cached
+        // input next time
+        let partial_path = path.with_extension("partial");
+
+        fs::write(&partial_path, input)
+            .and_then(|()| fs::rename(&partial_path, path))
+            .map_err(|err: io::Error| ClientError::Io {
+                url: path.display().to_string(),
+                reason: err.to_string(),
+            })?;
+
+        Ok(Download::Fetched)
+    }
+
+    /// Submits `answer` to `part` of `day`'s puzzle, and classifies the site's response
+    pub fn submit(&self, day: u8, part: Part, answer: &str) -> Result<Outcome, ClientError> {
+        let url = format!("{}/{}/day/{day}/answer", self.base_url, self.year);
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| ClientError::from_ureq(&url, err))?;
+
+        let html = response.into_string().map_err(|err| ClientError::Io {
+            url,
+            reason: err.to_string(),
+        })?;
+
+        Ok(Outcome::parse(&html))
+    }
+}
+
+fn user_agent() -> String {
+    format!(
+        "aoc/{} (Advent of Code command line tool)",
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Download {
+    /// The input was already on disk
+    Cached,
+    Fetched,
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    /// The site responded with an error status
+    Status {
+        url: String,
+        code: u16,
+        body: String,
+    },
+    /// The request couldn't be made, or the response couldn't be read or saved
+    Io { url: String, reason: String },
+}
+
+impl ClientError {
+    fn from_ureq(url: &str, err: ureq::Error) -> Self {
+        match err {
+            ureq::Error::Status(code, response) => ClientError::Status {
+                url: url.to_owned(),
+                code,
+                body: response.into_string().unwrap_or_default(),
+            },
+            ureq::Error::Transport(transport) => ClientError::Io {
+                url: url.to_owned(),
+                reason: transport.to_string(),
+            },
+        }
+    }
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ClientError::Status { url, code, body } => {
+                write!(f, "`{url}` responded with status {code}")?;
+
+                match code {
+                    400 | 500 => write!(f, ", the session cookie may have expired"),
+                    404 => write!(f, ", the puzzle may not be unlocked yet"),
+                    _ if !body.trim().is_empty() => write!(f, ": {}", body.trim()),
+                    _ => Ok(()),
+                }
+            }
+            ClientError::Io { url, reason } => write!(f, "failed to fetch `{url}`: {reason}"),
+        }
+    }
+}
+
+impl Error for ClientError {}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        env,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::*;
+
+    /// A request received by [`MockServer`]
+    #[derive(Clone, Debug)]
+    pub struct Request {
+        pub method: String,
+        pub url: String,
+        pub cookie: Option<String>,
+        pub user_agent: Option<String>,
+        pub body: String,
+    }
+
+    /// A local HTTP server which responds to every request with `status` and `body`, and records
+    /// the requests it receives.
+    pub struct MockServer {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        pub fn new(status: u16, body: &'static str) -> Self {
+            let server = Server::http("127.0.0.1:0").unwrap();
+            let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+
+            let recorded = Arc::clone(&requests);
+
+            thread::spawn(move || {
+                for mut request in server.incoming_requests() {
+                    let header = |name: &'static str| {
+                        request
+                            .headers()
+                            .iter()
+                            .find(|header| header.field.equiv(name))
+                            .map(|header| header.value.to_string())
+                    };
+
+                    let mut recorded_request = Request {
+                        method: request.method().to_string(),
+                        url: request.url().to_owned(),
+                        cookie: header("Cookie"),
+                        user_agent: header("User-Agent"),
+                        body: String::new(),
+                    };
+
+                    request
+                        .as_reader()
+                        .read_to_string(&mut recorded_request.body)
+                        .unwrap();
+
+                    recorded.lock().unwrap().push(recorded_request);
+
+                    let response = Response::from_string(body).with_status_code(status);
+                    request.respond(response).unwrap();
+                }
+            });
+
+            Self { base_url, requests }
+        }
+
+        pub fn config(&self) -> Config {
+            Config {
+                session: Some("abc123".to_owned()),
+                base_url: self.base_url.clone(),
+                ..Config::default()
+            }
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    /// Returns a path in a fresh temporary directory
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("input")
+    }
+
+    #[test]
+    fn input_sends_session_and_user_agent() {
+        let server = MockServer::new(200, "1abc2\n");
+        let client = Client::new(&server.config()).unwrap();
+
+        assert_eq!(client.input(1).unwrap(), "1abc2\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].url, "/2023/day/1/input");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+        assert!(requests[0].user_agent.as_ref().unwrap().starts_with("aoc/"));
+    }
+
+    #[test]
+    fn cached_inputs_are_not_downloaded_again() {
+        let server = MockServer::new(200, "1abc2\n");
+        let client = Client::new(&server.config()).unwrap();
+        let path = temp_path("cached");
+
+        assert_eq!(client.download_input(1, &path).unwrap(), Download::Fetched);
+        assert_eq!(client.download_input(1, &path).unwrap(), Download::Cached);
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn error_responses_are_not_cached() {
+        let server = MockServer::new(404, "Not Found");
+        let client = Client::new(&server.config()).unwrap();
+        let path = temp_path("error");
+
+        let err = client.download_input(25, &path).unwrap_err();
+
+        assert!(matches!(err, ClientError::Status { code: 404, .. }));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn submit_posts_the_answer() {
+        let server = MockServer::new(
+            200,
+            "<main><article><p>That's the right answer!  You are one gold star closer to \
+             restoring snow operations.</p></article></main>",
+        );
+        let client = Client::new(&server.config()).unwrap();
+
+        assert_eq!(client.submit(5, Part::Two, "46").unwrap(), Outcome::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].url, "/2023/day/5/answer");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=46");
+        assert!(requests[0]
+            .user_agent
+            .as_ref()
+            .unwrap()
+            .starts_with(&user_agent()));
+    }
+
+    #[test]
+    fn no_session_no_client() {
+        assert!(Client::new(&Config::default()).is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+};
+
+use serde::Deserialize;
+
+/// The config file used when none is given on the command line
+pub const DEFAULT_CONFIG_PATH: &str = "aoc.toml";
+
+/// The environment variable holding the session cookie, which overrides the config file
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Settings for talking to the Advent of Code site, read from `aoc.toml`:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// year = 2023
+/// base_url = "https://adventofcode.com"
+/// contact = "someone@example.com"
+/// ```
+#[derive(Debug, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// The value of the site's `session` cookie
+    pub session: Option<String>,
+    pub year: u16,
+    pub base_url: String,
+    /// Contact details added to the User-Agent, so the site's operator can get in touch
+    pub contact: Option<String>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            session: None,
+            year: 2023,
+            base_url: "https://adventofcode.com".to_owned(),
+            contact: None,
+        }
+    }
+}
+
+impl Config {
+    /// Reads the config at `path`, falling back to the defaults if there is no such file. The
+    /// session cookie is taken from `AOC_SESSION` instead if it is set.
+    pub fn load(path: &str) -> Result<Self, ConfigError> {
+        let mut config = match fs::read_to_string(path) {
+            Ok(text) => toml::from_str(&text).map_err(|err| ConfigError {
+                path: path.to_owned(),
+                reason: err.message().to_owned(),
+            })?,
+            Err(err) if err.kind() == ErrorKind::NotFound => Self::default(),
+            Err(err) => {
+                return Err(ConfigError {
+                    path: path.to_owned(),
+                    reason: err.to_string(),
+                })
+            }
+        };
+
+        if let Ok(session) = env::var(SESSION_VAR) {
+            config.session = Some(session);
+        }
+
+        Ok(config)
+    }
+}
+
+#[derive(Debug)]
+pub struct ConfigError {
+    path: String,
+    reason: String,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid config file `{}`: {}", self.path, self.reason)
+    }
+}
+
+impl Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_fields_use_defaults() {
+        let config: Config = toml::from_str("session = \"abc\"").unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                session: Some("abc".to_owned()),
+                ..Config::default()
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_fields_are_rejected() {
+        assert!(toml::from_str::<Config>("sesion = \"abc\"").is_err());
+    }
+}
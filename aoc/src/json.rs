@@ -0,0 +1,134 @@
+use std::time::Duration;
+
+use aoc_common::{Answer, Run};
+use serde::Serialize;
+
+use crate::answers::Verdict;
+
+/// A single answer in the JSON output, e.g.
+///
+/// ```json
+/// {"day":5,"part":2,"answer":"46","input":"aoc-05/example","input_hash":"7cc69d646a7e66d4",
+/// "parse_ns":4387,"solve_ns":84260,"verdict":"pass"}
+/// ```
+///
+/// An input with no answer for the part has a null `answer`, followed by the `reason` there isn't
+/// one.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+    pub input: &'a str,
+    /// The FNV-1a hash of the input text, so answers for different inputs can be told apart
+    pub input_hash: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Only present when verifying answers
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<Verdict<'a>>,
+}
+
+impl<'a> Record<'a> {
+    pub fn new(
+        day: u8,
+        path: &'a str,
+        input: &str,
+        run: &Run,
+        answer: &'a Answer,
+        verdict: Option<Verdict<'a>>,
+    ) -> Self {
+        Self {
+            day,
+            part: answer.part.number(),
+            answer: answer.value.as_deref(),
+            reason: answer.reason,
+            input: path,
+            input_hash: format!("{:016x}", fnv1a(input.as_bytes())),
+            parse_ns: nanos(run.parse_time),
+            solve_ns: nanos(answer.time),
+            verdict,
+        }
+    }
+}
+
+/// The 64-bit FNV-1a hash of `bytes`
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::Part;
+
+    use super::*;
+
+    #[test]
+    fn fnv1a_hashes() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn record_json() {
+        let answer = Answer {
+            part: Part::Two,
+            value: Some("46".to_owned()),
+            reason: None,
+            time: Duration::from_micros(84),
+        };
+        let run = Run {
+            parse_time: Duration::from_nanos(4387),
+            answers: vec![answer.clone()],
+        };
+
+        let record = Record::new(5, "aoc-05/example", "", &run, &answer, None);
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r#"{"day":5,"part":2,"answer":"46","input":"aoc-05/example","input_hash":"cbf29ce484222325","parse_ns":4387,"solve_ns":84000}"#
+        );
+
+        let record = Record::new(
+            5,
+            "aoc-05/example",
+            "",
+            &run,
+            &answer,
+            Some(Verdict::Fail { expected: "56" }),
+        );
+
+        assert!(serde_json::to_string(&record)
+            .unwrap()
+            .ends_with(r#""solve_ns":84000,"verdict":"fail","expected":"56"}"#));
+    }
+
+    #[test]
+    fn missing_answer_json() {
+        let answer = Answer {
+            part: Part::Two,
+            value: None,
+            reason: Some("no conjunction feeding `rx`"),
+            time: Duration::from_micros(5),
+        };
+        let run = Run {
+            parse_time: Duration::from_nanos(49),
+            answers: vec![answer.clone()],
+        };
+
+        let record = Record::new(20, "input", "", &run, &answer, None);
+
+        assert!(serde_json::to_string(&record)
+            .unwrap()
+            .starts_with(r#"{"day":20,"part":2,"answer":null,"reason":"no conjunction feeding `rx`","input":"input","#));
+    }
+}
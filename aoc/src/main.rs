@@ -0,0 +1,624 @@
+mod answers;
+mod bench;
+mod client;
+mod config;
+mod json;
+mod submit;
+
+use std::{
+    fs,
+    path::Path,
+    process::ExitCode,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use answers::{Manifest, Verdict, DEFAULT_MANIFEST_PATH};
+use aoc_common::{generate, read_input, solve, Answer, ParseError, Part, Run, STDIN_PATH};
+use bench::Report;
+use clap::{Args, Parser, Subcommand};
+use client::{Client, Download};
+use config::{Config, DEFAULT_CONFIG_PATH, SESSION_VAR};
+use json::Record;
+use submit::{Guesses, Outcome, DEFAULT_GUESSES_PATH};
+
+type Solver = fn(&str, &[Part]) -> Result<Run, ParseError>;
+
+/// Solvers for each day, indexed by `day - 1`
+const SOLVERS: [Solver; 25] = [
+    solve::<aoc_01::Day01>,
+    solve::<aoc_02::Day02>,
+    solve::<aoc_03::Day03>,
+    solve::<aoc_04::Day04>,
+    solve::<aoc_05::Day05>,
+    solve::<aoc_06::Day06>,
+    solve::<aoc_07::Day07>,
+    solve::<aoc_08::Day08>,
+    solve::<aoc_09::Day09>,
+    solve::<aoc_10::Day10>,
+    solve::<aoc_11::Day11>,
+    solve::<aoc_12::Day12>,
+    solve::<aoc_13::Day13>,
+    solve::<aoc_14::Day14>,
+    solve::<aoc_15::Day15>,
+    solve::<aoc_16::Day16>,
+    solve::<aoc_17::Day17>,
+    solve::<aoc_18::Day18>,
+    solve::<aoc_19::Day19>,
+    solve::<aoc_20::Day20>,
+    solve::<aoc_21::Day21>,
+    solve::<aoc_22::Day22>,
+    solve::<aoc_23::Day23>,
+    solve::<aoc_24::Day24>,
+    solve::<aoc_25::Day25>,
+];
+
+type InputGenerator = fn(u64, Option<usize>) -> String;
+
+/// Input generators for each day, indexed by `day - 1`
+const GENERATORS: [InputGenerator; 25] = [
+    generate::<aoc_01::Day01>,
+    generate::<aoc_02::Day02>,
+    generate::<aoc_03::Day03>,
+    generate::<aoc_04::Day04>,
+    generate::<aoc_05::Day05>,
+    generate::<aoc_06::Day06>,
+    generate::<aoc_07::Day07>,
+    generate::<aoc_08::Day08>,
+    generate::<aoc_09::Day09>,
+    generate::<aoc_10::Day10>,
+    generate::<aoc_11::Day11>,
+    generate::<aoc_12::Day12>,
+    generate::<aoc_13::Day13>,
+    generate::<aoc_14::Day14>,
+    generate::<aoc_15::Day15>,
+    generate::<aoc_16::Day16>,
+    generate::<aoc_17::Day17>,
+    generate::<aoc_18::Day18>,
+    generate::<aoc_19::Day19>,
+    generate::<aoc_20::Day20>,
+    generate::<aoc_21::Day21>,
+    generate::<aoc_22::Day22>,
+    generate::<aoc_23::Day23>,
+    generate::<aoc_24::Day24>,
+    generate::<aoc_25::Day25>,
+];
+
+/// Runs and times the Advent of Code solutions
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a day's puzzle and prints the answers
+    Run(RunArgs),
+    /// Downloads a day's puzzle input, unless it has already been downloaded
+    Download(DownloadArgs),
+    /// Times each step of the solutions over several runs
+    Bench(BenchArgs),
+    /// Submits an answer to a day's puzzle, unless it's known to be wrong
+    Submit(SubmitArgs),
+    /// Generates a random puzzle input for a day
+    Generate(GenerateArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// The day to run [default: every day with a puzzle input, or with `--verify` every input in
+    /// the answers manifest]
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Only solve this part, 1 or 2
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// The puzzle input file, or `-` for stdin [default: aoc-<day>/input]
+    #[arg(long, requires = "day")]
+    input: Option<String>,
+
+    /// Check the answers against the answers manifest
+    #[arg(long)]
+    verify: bool,
+
+    /// Record the answers in the answers manifest
+    #[arg(long, requires = "day", conflicts_with = "verify")]
+    record: bool,
+
+    /// The answers manifest
+    #[arg(long, default_value = DEFAULT_MANIFEST_PATH)]
+    answers: String,
+
+    /// Print each answer as a line of JSON, with its timings and a hash of the input
+    #[arg(long)]
+    json: bool,
+}
+
+#[derive(Args)]
+struct DownloadArgs {
+    /// The day to download
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Where to save the input [default: aoc-<day>/input]
+    #[arg(long)]
+    output: Option<String>,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// The days to benchmark [default: every day with a puzzle input]
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    days: Vec<u8>,
+
+    /// How many times to run each step
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+
+    /// Only benchmark this part, 1 or 2
+    #[arg(long)]
+    part: Option<Part>,
+
+    /// The puzzle input file, when benchmarking a single day [default: aoc-<day>/input]
+    #[arg(long)]
+    input: Option<String>,
+
+    /// Save the results to this file
+    #[arg(long)]
+    save: Option<String>,
+
+    /// Compare the results with those saved in this file
+    #[arg(long)]
+    baseline: Option<String>,
+
+    /// The percentage increase in median time from the baseline which counts as a regression
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// The day to submit an answer for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The part to submit an answer for, 1 or 2
+    part: Part,
+
+    /// The answer to submit [default: the solver's answer for the puzzle input]
+    answer: Option<String>,
+
+    /// The puzzle input to solve when no answer is given [default: aoc-<day>/input]
+    #[arg(long, conflicts_with = "answer")]
+    input: Option<String>,
+
+    /// The file recording answers which were wrong
+    #[arg(long, default_value = DEFAULT_GUESSES_PATH)]
+    guesses: String,
+
+    #[command(flatten)]
+    site: SiteArgs,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// The day to generate an input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// The size of the input, which means something different for each day [default: roughly the
+    /// size of a real input]
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// The seed for the random number generator, which is printed when not given [default: the
+    /// current time]
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Where to save the input [default: stdout]
+    #[arg(long)]
+    output: Option<String>,
+}
+
+/// Options for talking to the Advent of Code site
+#[derive(Args)]
+struct SiteArgs {
+    /// The config file holding the session cookie
+    #[arg(long, default_value = DEFAULT_CONFIG_PATH)]
+    config: String,
+
+    /// The puzzle year, overriding the config file
+    #[arg(long)]
+    year: Option<u16>,
+
+    /// The site's base URL, overriding the config file
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+impl SiteArgs {
+    /// Creates a client from the config file and command line, or prints why it can't
+    fn client(self) -> Option<Client> {
+        let mut config = match Config::load(&self.config) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("error: {err}");
+                return None;
+            }
+        };
+
+        config.year = self.year.unwrap_or(config.year);
+        config.base_url = self.base_url.unwrap_or(config.base_url);
+
+        let client = Client::new(&config);
+
+        if client.is_none() {
+            eprintln!(
+                "error: no session cookie, set `{SESSION_VAR}` or `session` in `{}`",
+                self.config
+            );
+        }
+
+        client
+    }
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run(args) => run(args),
+        Command::Download(args) => download(args),
+        Command::Bench(args) => bench(args),
+        Command::Submit(args) => submit(args),
+        Command::Generate(args) => generate_input(args),
+    }
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let parts = args.part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+
+    let mut manifest = if args.verify || args.record {
+        match Manifest::load(&args.answers) {
+            Ok(manifest) => manifest,
+            Err(err) => {
+                eprintln!("error: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        Manifest::default()
+    };
+
+    let inputs: Vec<_> = match args.day {
+        Some(day) => vec![(day, args.input.unwrap_or_else(|| default_input_path(day)))],
+        None if args.verify => manifest
+            .inputs()
+            .map(|(day, input)| (day, input.to_owned()))
+            .collect(),
+        None => available_inputs(),
+    };
+
+    if args.record && inputs.iter().any(|(_, path)| path == STDIN_PATH) {
+        eprintln!("error: answers can't be recorded for stdin");
+        return ExitCode::FAILURE;
+    }
+
+    let mut success = true;
+
+    for (day, path) in &inputs {
+        let Some((input, run)) = solve_input(*day, path, &parts) else {
+            success = false;
+            continue;
+        };
+
+        if !args.json {
+            println!("day {day}, {path} (parsed in {:.2?})", run.parse_time);
+        }
+
+        for answer in &run.answers {
+            let verdict = args
+                .verify
+                .then(|| manifest.verify(*day, path, answer.part, &answer.to_string()));
+
+            success &= !matches!(verdict, Some(Verdict::Fail { .. }));
+
+            if args.json {
+                let record = Record::new(*day, path, &input, &run, answer, verdict);
+                println!("{}", serde_json::to_string(&record).unwrap());
+                continue;
+            }
+
+            let line = format!("part {}: {answer} ({:.2?})", answer.part, answer.time);
+
+            match verdict {
+                Some(verdict) => println!("{line} {verdict}"),
+                None => println!("{line}"),
+            }
+        }
+
+        if args.record {
+            for answer in &run.answers {
+                let Some(value) = &answer.value else {
+                    eprintln!(
+                        "error: can't record part {} of `{path}`, which has {answer}",
+                        answer.part
+                    );
+                    success = false;
+                    continue;
+                };
+
+                manifest.record(*day, path, answer.part, value);
+            }
+        }
+    }
+
+    if args.record {
+        if let Err(err) = manifest.save(&args.answers) {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Reads the input at `path` and solves `parts` of it, or prints the error
+fn solve_input(day: u8, path: &str, parts: &[Part]) -> Option<(String, Run)> {
+    let input = match read_input(path) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return None;
+        }
+    };
+
+    match SOLVERS[day as usize - 1](&input, parts) {
+        Ok(run) => Some((input, run)),
+        Err(err) => {
+            eprintln!("error: failed to parse `{path}`: {err}");
+            None
+        }
+    }
+}
+
+fn download(args: DownloadArgs) -> ExitCode {
+    let Some(client) = args.site.client() else {
+        return ExitCode::FAILURE;
+    };
+
+    let path = args.output.unwrap_or_else(|| default_input_path(args.day));
+
+    match client.download_input(args.day, Path::new(&path)) {
+        Ok(Download::Cached) => println!("`{path}` is already downloaded"),
+        Ok(Download::Fetched) => println!("saved day {} input to `{path}`", args.day),
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let parts = args.part.map_or(Part::ALL.to_vec(), |part| vec![part]);
+
+    let inputs: Vec<_> = match (&args.days[..], args.input) {
+        (&[day], Some(input)) => vec![(day, input)],
+        (_, Some(_)) => {
+            eprintln!("error: `--input` can only be used when benchmarking a single day");
+            return ExitCode::FAILURE;
+        }
+        ([], None) => available_inputs(),
+        (days, None) => days
+            .iter()
+            .map(|&day| (day, default_input_path(day)))
+            .collect(),
+    };
+
+    let baseline = match args.baseline.as_deref().map(Report::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut report = Report::default();
+    let mut success = true;
+
+    for (day, path) in inputs {
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("error: {err}");
+                success = false;
+                continue;
+            }
+        };
+
+        let steps = match bench::measure(SOLVERS[day as usize - 1], &input, &parts, args.runs) {
+            Ok(steps) => steps,
+            Err(err) => {
+                eprintln!("error: failed to parse `{path}`: {err}");
+                success = false;
+                continue;
+            }
+        };
+
+        println!("day {day}, {path} ({} runs)", args.runs);
+
+        for (&step, stats) in &steps {
+            let baseline_stats = baseline.as_ref().and_then(|report| report.get(day, step));
+
+            match baseline_stats {
+                Some(baseline_stats) => {
+                    let change = stats.change(baseline_stats) * 100.0;
+                    let regressed = change > args.threshold;
+                    success &= !regressed;
+
+                    println!(
+                        "{step}: {stats} ({change:+.1}% from baseline{})",
+                        if regressed { ", REGRESSION" } else { "" }
+                    );
+                }
+                None => println!("{step}: {stats}"),
+            }
+        }
+
+        report.insert(day, steps);
+    }
+
+    if let Some(path) = &args.save {
+        if let Err(err) = report.save(path) {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn submit(args: SubmitArgs) -> ExitCode {
+    let answer = match args.answer {
+        Some(answer) => answer,
+        None => {
+            let path = args.input.unwrap_or_else(|| default_input_path(args.day));
+
+            let Some((_, mut run)) = solve_input(args.day, &path, &[args.part]) else {
+                return ExitCode::FAILURE;
+            };
+
+            match run.answers.remove(0) {
+                Answer {
+                    value: Some(value), ..
+                } => value,
+                answer => {
+                    eprintln!("error: not submitting, `{path}` has {answer}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+
+    let mut guesses = match Guesses::load(&args.guesses) {
+        Ok(guesses) => guesses,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(outcome) = guesses.check(args.day, args.part, &answer) {
+        eprintln!("error: not submitting {answer}, it's known to be {outcome}");
+        return ExitCode::FAILURE;
+    }
+
+    let Some(client) = args.site.client() else {
+        return ExitCode::FAILURE;
+    };
+
+    let outcome = match client.submit(args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(err) => {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!(
+        "day {}, part {}: {answer} is {outcome}",
+        args.day, args.part
+    );
+
+    if guesses.record(args.day, args.part, &answer, &outcome) {
+        if let Err(err) = guesses.save(&args.guesses) {
+            eprintln!("error: {err}");
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if outcome == Outcome::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn generate_input(args: GenerateArgs) -> ExitCode {
+    let seed = args.seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_nanos() as u64);
+
+        eprintln!("seed {seed}");
+        seed
+    });
+
+    let input = GENERATORS[args.day as usize - 1](seed, args.size);
+
+    match args.output {
+        Some(path) => {
+            if let Err(err) = fs::write(&path, input) {
+                eprintln!("error: failed to write `{path}`: {err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        None => print!("{input}"),
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn default_input_path(day: u8) -> String {
+    format!("aoc-{day:02}/input")
+}
+
+/// Returns every day whose puzzle input is at the default path
+fn available_inputs() -> Vec<(u8, String)> {
+    (1..=25)
+        .map(|day| (day, default_input_path(day)))
+        .filter(|(_, path)| Path::new(path).exists())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn small_generated_inputs_solve() {
+        for (day, (generate, solve)) in (1..).zip(GENERATORS.iter().zip(SOLVERS)) {
+            // sizes below a generator's minimum are raised to it
+            for size in 0..=5 {
+                for seed in 0..3 {
+                    let input = generate(seed, Some(size));
+
+                    let run = solve(&input, &Part::ALL).unwrap_or_else(|err| {
+                        panic!("day {day}: {err} in the input of size {size}:\n{input}")
+                    });
+
+                    if let Some(answer) = run.answers.iter().find(|answer| answer.value.is_none()) {
+                        panic!(
+                            "day {day}: part {} has {answer} for the input of size {size}:\n{input}",
+                            answer.part
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
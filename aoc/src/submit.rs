@@ -0,0 +1,386 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    fmt::{self, Display},
+    fs,
+    io::ErrorKind,
+    time::Duration,
+};
+
+use aoc_common::Part;
+
+/// The guesses file used when none is given on the command line
+pub const DEFAULT_GUESSES_PATH: &str = "guesses.toml";
+
+/// The site's verdict on a submitted answer
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// An answer was submitted too recently, and another can't be submitted for `wait`
+    RateLimited {
+        wait: Option<Duration>,
+    },
+    /// The part has already been solved, or the previous part hasn't been
+    AlreadySolved,
+    /// A response which couldn't be classified, with the text of its message
+    Unknown(String),
+}
+
+impl Outcome {
+    /// Classifies the HTML page the site responds to a submission with
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+
+        if text.contains("That's the right answer") {
+            Outcome::Correct
+        } else if text.contains("That's not the right answer") {
+            if text.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if text.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if text.contains("You gave an answer too recently") {
+            Outcome::RateLimited {
+                wait: wait_time(&text),
+            }
+        } else if text.contains("You don't seem to be solving the right level") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unknown(text)
+        }
+    }
+
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Outcome::RateLimited { wait: None } => write!(f, "rate limited"),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unknown(text) => write!(f, "unrecognised response: {text}"),
+        }
+    }
+}
+
+/// Returns the text of the `<article>` holding the site's message, with the markup removed
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(html, |(_, rest)| {
+            rest.split_once("</article>")
+                .map_or(rest, |(inner, _)| inner)
+        });
+
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => (),
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parses the wait from a message like "You have 1m 23s left to wait."
+fn wait_time(text: &str) -> Option<Duration> {
+    let (_, rest) = text.split_once("You have ")?;
+    let (wait, _) = rest.split_once(" left to wait")?;
+
+    wait.split_whitespace()
+        .map(|amount| {
+            let unit = match amount.chars().last()? {
+                'h' => 3600,
+                'm' => 60,
+                's' => 1,
+                _ => return None,
+            };
+
+            amount[..amount.len() - 1]
+                .parse::<u64>()
+                .ok()
+                .map(|n| n * unit)
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Answers which the site has said are wrong, keyed by day and part, so they aren't submitted
+/// again.
+///
+/// Stored as TOML, with a table for each day and part:
+///
+/// ```toml
+/// [5.1]
+/// "35" = "too high"
+/// "abc" = "wrong"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Guesses {
+    days: BTreeMap<u8, BTreeMap<Part, BTreeMap<String, Outcome>>>,
+}
+
+/// The raw TOML representation of [`Guesses`]
+type Tables = BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>;
+
+impl Guesses {
+    /// Reads the guesses at `path`. A missing file has no guesses.
+    pub fn load(path: &str) -> Result<Self, GuessesError> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(GuessesError::new(path, err)),
+        };
+
+        Self::parse(&text).map_err(|reason| GuessesError::new(path, reason))
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), GuessesError> {
+        fs::write(path, self.to_string()).map_err(|err| GuessesError::new(path, err))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        let tables: Tables = toml::from_str(text).map_err(|err| err.message().to_owned())?;
+        let mut days = BTreeMap::new();
+
+        for (day, parts) in tables {
+            let day = day
+                .parse()
+                .ok()
+                .filter(|day| (1..=25).contains(day))
+                .ok_or_else(|| format!("expected a day from 1 to 25, found `{day}`"))?;
+
+            let parts = parts
+                .into_iter()
+                .map(|(part, guesses)| {
+                    let guesses = guesses
+                        .into_iter()
+                        .map(|(answer, outcome)| {
+                            let outcome = match outcome.as_str() {
+                                "wrong" => Outcome::Wrong,
+                                "too high" => Outcome::TooHigh,
+                                "too low" => Outcome::TooLow,
+                                _ => {
+                                    return Err(format!(
+                                        "expected `wrong`, `too high` or `too low`, found \
+                                         `{outcome}`"
+                                    ))
+                                }
+                            };
+
+                            Ok((answer, outcome))
+                        })
+                        .collect::<Result<_, String>>()?;
+
+                    Ok((part.parse()?, guesses))
+                })
+                .collect::<Result<_, String>>()?;
+
+            days.insert(day, parts);
+        }
+
+        Ok(Self { days })
+    }
+
+    /// Returns the outcome `answer` is known to have, if it's a previous guess, or a numeric
+    /// answer at or beyond a guess which was too high or too low.
+    pub fn check(&self, day: u8, part: Part, answer: &str) -> Option<Outcome> {
+        let guesses = self.days.get(&day)?.get(&part)?;
+
+        if let Some(outcome) = guesses.get(answer) {
+            return Some(outcome.clone());
+        }
+
+        let answer: i64 = answer.parse().ok()?;
+
+        guesses.iter().find_map(|(guess, outcome)| {
+            let guess: i64 = guess.parse().ok()?;
+
+            match outcome {
+                Outcome::TooHigh if answer >= guess => Some(Outcome::TooHigh),
+                Outcome::TooLow if answer <= guess => Some(Outcome::TooLow),
+                _ => None,
+            }
+        })
+    }
+
+    /// Records `answer` if `outcome` says it's wrong. Returns whether it was recorded.
+    pub fn record(&mut self, day: u8, part: Part, answer: &str, outcome: &Outcome) -> bool {
+        if !outcome.is_wrong() {
+            return false;
+        }
+
+        self.days
+            .entry(day)
+            .or_default()
+            .entry(part)
+            .or_default()
+            .insert(answer.to_owned(), outcome.clone());
+
+        true
+    }
+}
+
+impl Display for Guesses {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let tables: Tables = self
+            .days
+            .iter()
+            .map(|(day, parts)| {
+                let parts = parts
+                    .iter()
+                    .map(|(part, guesses)| {
+                        let guesses = guesses
+                            .iter()
+                            .map(|(answer, outcome)| (answer.clone(), outcome.to_string()))
+                            .collect();
+
+                        (part.to_string(), guesses)
+                    })
+                    .collect();
+
+                (day.to_string(), parts)
+            })
+            .collect();
+
+        let text = toml::to_string(&tables).map_err(|_| fmt::Error)?;
+        write!(f, "{text}")
+    }
+}
+
+#[derive(Debug)]
+pub struct GuessesError {
+    path: String,
+    reason: String,
+}
+
+impl GuessesError {
+    fn new(path: &str, reason: impl Display) -> Self {
+        Self {
+            path: path.to_owned(),
+            reason: reason.to_string(),
+        }
+    }
+}
+
+impl Display for GuessesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid guesses file `{}`: {}", self.path, self.reason)
+    }
+}
+
+impl Error for GuessesError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><head><title>Day 5 - Advent of Code 2023</title></head>\
+             <body><main>\n<article><p>{message}</p></article>\n</main></body></html>"
+        )
+    }
+
+    #[test]
+    fn classify_responses() {
+        let cases = [
+            (
+                "That's the right answer!  You are <span class=\"day-success\">one gold star\
+                 </span> closer to restoring snow operations.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full \
+                 input data.",
+                Outcome::Wrong,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  If you're stuck, make \
+                 sure you're using the full input data.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.",
+                Outcome::TooLow,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 23s left to wait. <a href=\"/2023/day/5\">\
+                 [Return to Day 5]</a>",
+                Outcome::RateLimited {
+                    wait: Some(Duration::from_secs(83)),
+                },
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 45s left to wait.",
+                Outcome::RateLimited {
+                    wait: Some(Duration::from_secs(45)),
+                },
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it?",
+                Outcome::AlreadySolved,
+            ),
+            (
+                "Something   <em>new</em>",
+                Outcome::Unknown("Something new".to_owned()),
+            ),
+        ];
+
+        for (message, outcome) in cases {
+            assert_eq!(Outcome::parse(&page(message)), outcome, "{message}");
+        }
+    }
+
+    #[test]
+    fn check_guesses() {
+        let mut guesses = Guesses::default();
+
+        assert!(guesses.record(5, Part::One, "100", &Outcome::TooHigh));
+        assert!(guesses.record(5, Part::One, "10", &Outcome::TooLow));
+        assert!(guesses.record(5, Part::One, "abc", &Outcome::Wrong));
+        assert!(!guesses.record(5, Part::One, "35", &Outcome::Correct));
+        assert!(!guesses.record(5, Part::One, "36", &Outcome::AlreadySolved));
+
+        assert_eq!(guesses.check(5, Part::One, "abc"), Some(Outcome::Wrong));
+        assert_eq!(guesses.check(5, Part::One, "100"), Some(Outcome::TooHigh));
+        assert_eq!(guesses.check(5, Part::One, "150"), Some(Outcome::TooHigh));
+        assert_eq!(guesses.check(5, Part::One, "-5"), Some(Outcome::TooLow));
+        assert_eq!(guesses.check(5, Part::One, "35"), None);
+        assert_eq!(guesses.check(5, Part::Two, "abc"), None);
+        assert_eq!(guesses.check(6, Part::One, "abc"), None);
+    }
+
+    #[test]
+    fn guesses_round_trip() {
+        let mut guesses = Guesses::default();
+        guesses.record(5, Part::One, "100", &Outcome::TooHigh);
+        guesses.record(5, Part::Two, "abc", &Outcome::Wrong);
+        guesses.record(12, Part::One, "21", &Outcome::TooLow);
+
+        assert_eq!(Guesses::parse(&guesses.to_string()), Ok(guesses));
+        assert!(Guesses::parse("[5.1]\n\"35\" = \"correct\"").is_err());
+    }
+}
@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc_common::Solution;
+use aoc_02::Day02;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = Day02::parse(input);
+});